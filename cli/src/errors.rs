@@ -0,0 +1,48 @@
+use std::fmt;
+
+use crate::StegoError;
+
+/// Error returned by the wasm component, kept apart from the rest so the
+/// process can exit with a code that tells the failure reason.
+#[derive(Debug)]
+pub(crate) struct StegoFailure(pub(crate) StegoError);
+
+impl StegoFailure {
+    pub(crate) fn exit_code(&self) -> u8 {
+        match self.0 {
+            StegoError::UnsupportedFormat(_) => 3,
+            StegoError::WrongPixelLayout(_) => 4,
+            StegoError::CapacityExceeded => 5,
+            StegoError::NoPayloadFound => 6,
+            StegoError::CorruptPayload(_) => 7,
+            StegoError::InvalidUtf8 => 8,
+            StegoError::InvalidOption(_) => 9,
+            StegoError::PasswordRequired => 10,
+            StegoError::WrongPassword => 11,
+            StegoError::IdentityRequired => 12,
+            StegoError::NotARecipient => 13,
+            StegoError::IncompleteSplit(_) => 14,
+        }
+    }
+}
+
+impl fmt::Display for StegoFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            StegoError::UnsupportedFormat(reason) => write!(f, "The image format is not supported: {reason}"),
+            StegoError::WrongPixelLayout(reason) => write!(f, "The image can't carry a secret: {reason}"),
+            StegoError::CapacityExceeded => write!(f, "The secret is too long to fit into the image"),
+            StegoError::NoPayloadFound => write!(f, "The image doesn't contain a hidden secret"),
+            StegoError::CorruptPayload(reason) => write!(f, "The hidden secret is damaged: {reason}"),
+            StegoError::InvalidUtf8 => write!(f, "The hidden secret is not valid UTF-8 text"),
+            StegoError::InvalidOption(reason) => write!(f, "Invalid option: {reason}"),
+            StegoError::PasswordRequired => write!(f, "The hidden secret is encrypted, a password is required"),
+            StegoError::WrongPassword => write!(f, "The password doesn't decrypt the hidden secret"),
+            StegoError::IdentityRequired => write!(f, "The hidden secret is encrypted to recipients, an identity is required"),
+            StegoError::NotARecipient => write!(f, "None of the identities is a recipient of the hidden secret"),
+            StegoError::IncompleteSplit(reason) => write!(f, "The images don't hold the whole split secret or enough shares of it: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for StegoFailure {}
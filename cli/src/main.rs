@@ -1,31 +1,52 @@
 mod wasm;
 mod options;
+mod errors;
 
 use anyhow::Context;
 use wasmtime::component::*;
 use options::*;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
 use clio::{InputPath, OutputPath};
 use wasmtime::{component::Component, Engine, Store};
 use wasmtime_wasi::WasiCtx;
-use std::fs;
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+use crate::errors::StegoFailure;
 use crate::wasm::MyState;
 
 const WASM_BYTES: &[u8] = include_bytes!(env!("WASM_STEGANOGRAPHY_FILE_PATH"));
 
 wasmtime::component::bindgen!("steganography" in "../wasm/wit/world.wit");
 
-fn main() -> anyhow::Result<()> {
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err:#}");
+            let code = err.downcast_ref::<StegoFailure>().map_or(1, StegoFailure::exit_code);
+
+            ExitCode::from(code)
+        }
+    }
+}
+
+fn run() -> anyhow::Result<()> {
     dotenv::dotenv().ok();
 
-    let args = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let args = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
     let engine = Engine::default();
     let component = Component::from_binary(&engine, WASM_BYTES).with_context(|| "Failed to open the wasm component.")?;
     let mut builder = WasiCtx::builder();
     let mut store = Store::new(&engine, MyState {
         ctx: builder.build(),
-        table: ResourceTable::new() 
+        table: ResourceTable::new()
     });
     let mut linker = Linker::<MyState>::new(&engine);
     wasmtime_wasi::p2::add_to_linker_sync(&mut linker).context("Failed adding the imported log function to the wasm linker")?;
@@ -38,28 +59,237 @@ fn main() -> anyhow::Result<()> {
     let steg = Steganography::new(&mut store, &instance)?;
 
     match args.command {
-        Command::Encode { secret, input_file, output_file } => {
-            let file_type = input_file.path().extension().and_then(|s| s.to_str()).expect("unknown file extension").to_lowercase();
-            let image_bytes = fs::read(InputPath::path(&input_file).path()).with_context(|| format!("Failed reading file: {}", &output_file.path()))?;
-            let encoded_image = match file_type.as_str() {
-                "bmp" => steg.call_encode_secret_into_bmp(&mut store, &secret.to_owned(), &image_bytes).expect("Failed call to wasm method."),
-                "jpg" | "jpeg" => steg.call_encode_secret_into_jpeg(&mut store, &secret.to_owned(), &image_bytes).expect("Failed call to wasm method."),
-                _ => panic!("unsupported file type: {}", file_type),
+        Command::Encode { secret, secret_file, file, media_type, add, input_files, output_file, split, threshold, bits_per_channel, quality, key, password, recipients, signing_key_file, error_correction, decoy, decoy_password } => {
+            // STEGO_DECOY_PASSWORD may well be set for every encode, only a --decoy-password given
+            // by hand tells the user expects a decoy
+            let decoy_password_source = matches.subcommand_matches("encode").and_then(|encode| encode.value_source("decoy_password"));
+
+            if decoy.is_none() && decoy_password_source == Some(ValueSource::CommandLine) {
+                Cli::command().error(ErrorKind::MissingRequiredArgument, "--decoy-password needs --decoy, without it no decoy would be hidden").exit();
+            }
+
+            let images = input_files.iter().map(read_image).collect::<anyhow::Result<Vec<_>>>()?;
+            let signing_key = match signing_key_file {
+                Some(signing_key_file) => Some(read_keys(&signing_key_file)?.into_iter().next().with_context(|| format!("No signing key in file: {}", &signing_key_file.path()))?),
+                None => None,
+            };
+            let decoy = decoy.zip(decoy_password).map(|(message, password)| Decoy { message, password });
+            let options = EmbedOptions { bits_per_channel, quality, key, password, recipients, signing_key, error_correction, decoy };
+            // clap makes sure one of them or --add is set, text secrets are flagged as such in the image
+            let content = match (secret, secret_file, file) {
+                (Some(secret), _, _) => HiddenContent::Text(secret),
+                (None, Some(secret_file), _) => HiddenContent::Binary(fs::read(secret_file.path().path()).with_context(|| format!("Failed reading file: {}", &secret_file.path()))?),
+                (None, None, Some(file)) => HiddenContent::File(read_file_payload(&file, media_type)?),
+                (None, None, None) => HiddenContent::Archive(add.iter().map(|file| read_file_payload(file, None)).collect::<anyhow::Result<Vec<_>>>()?),
             };
-            fs::write(OutputPath::path(&output_file).path(), &encoded_image).with_context(|| format!("Failed writing file: {}", &output_file.path()))?;
+
+            // the component tells the image format from its content
+            match (split, images.as_slice()) {
+                (Some(split_dir), _) => {
+                    let encoded_images = match threshold {
+                        Some(threshold) => steg.call_encode_shared_with_options(&mut store, &content, &images, threshold, &options),
+                        None => steg.call_encode_split_with_options(&mut store, &content, &images, &options),
+                    }.context("Failed call to wasm method.")?.map_err(StegoFailure)?;
+                    fs::create_dir_all(&split_dir).with_context(|| format!("Failed creating directory: {}", split_dir.display()))?;
+                    let mut names = HashSet::new();
+                    let mut paths = Vec::with_capacity(input_files.len());
+
+                    for input_file in &input_files {
+                        let name = input_file.path().path().file_name().with_context(|| format!("Not a file name: {}", &input_file.path()))?;
+                        anyhow::ensure!(names.insert(name), "Several input images are named {}, they would overwrite each other", name.to_string_lossy());
+                        paths.push(split_dir.join(name));
+                    }
+
+                    // the cover images themselves may well be in that directory
+                    ensure_none_exist(&paths)?;
+
+                    for (path, encoded_image) in paths.iter().zip(encoded_images) {
+                        create_new_file(path, &encoded_image)?;
+                    }
+                },
+                (None, [image_bytes]) => {
+                    let encoded_image = match &content {
+                        HiddenContent::Text(secret) => steg.call_encode_secret_with_options(&mut store, secret, image_bytes, &options),
+                        HiddenContent::Binary(payload) => steg.call_encode_bytes_with_options(&mut store, payload, image_bytes, &options),
+                        HiddenContent::File(file) => steg.call_encode_file_with_options(&mut store, file, image_bytes, &options),
+                        HiddenContent::Archive(files) => steg.call_encode_archive_with_options(&mut store, files, image_bytes, &options),
+                    }.context("Failed call to wasm method.")?.map_err(StegoFailure)?;
+
+                    fs::write(OutputPath::path(&output_file).path(), &encoded_image).with_context(|| format!("Failed writing file: {}", &output_file.path()))?;
+                },
+                (None, _) => anyhow::bail!("Several input images need --split to spread the secret across them"),
+            }
         },
-        Command::Decode { input_file } => {
-            let file_type = input_file.path().extension().and_then(|s| s.to_str()).expect("unknown file extension").to_lowercase();
+        Command::Decode { input_files, output_file, extract_dir, list, key, password, identities } => {
+            let images = input_files.iter().map(read_image).collect::<anyhow::Result<Vec<_>>>()?;
+            let identities = identities.iter().map(read_keys).collect::<anyhow::Result<Vec<_>>>()?.concat();
+            // like encoding, the component tells the image format from its content
+            let options = ExtractOptions { key, password, identities };
+
+            let decoded = match images.as_slice() {
+                [image_bytes] => steg.call_decode_with_options(&mut store, image_bytes, &options),
+                _ => steg.call_decode_split_with_options(&mut store, &images, &options),
+            }.context("Failed call to wasm method.")?.map_err(StegoFailure)?;
+
+            if list {
+                let files = match decoded.content {
+                    HiddenContent::File(file) => vec![file],
+                    HiddenContent::Archive(files) => files,
+                    HiddenContent::Text(_) | HiddenContent::Binary(_) => anyhow::bail!("The hidden secret isn't a file or an archive, there's nothing to list"),
+                };
+
+                for file in files {
+                    println!("{} ({}, {} bytes)", file.name, file.media_type, file.content.len());
+                }
+            } else {
+                match (decoded.content, output_file) {
+                    (HiddenContent::File(file), Some(output_file)) => write_file(output_file.path().path(), &file)?,
+                    (HiddenContent::File(file), None) => extract_files(extract_dir.as_deref(), &[file])?,
+                    (HiddenContent::Archive(files), None) => extract_files(extract_dir.as_deref(), &files)?,
+                    (HiddenContent::Archive(_), Some(_)) => anyhow::bail!("The hidden secret is an archive of several files, extract it with --extract-dir instead of --output-file"),
+                    (HiddenContent::Text(secret), None) => println!("{}", secret),
+                    (HiddenContent::Binary(payload), None) => println!("{}", String::from_utf8(payload).map_err(|_| StegoFailure(StegoError::InvalidUtf8))?),
+                    (HiddenContent::Text(secret), Some(output_file)) => fs::write(output_file.path().path(), secret).with_context(|| format!("Failed writing file: {}", &output_file.path()))?,
+                    (HiddenContent::Binary(payload), Some(output_file)) => fs::write(output_file.path().path(), payload).with_context(|| format!("Failed writing file: {}", &output_file.path()))?,
+                }
+            }
+            report_decoded(&decoded.signature, decoded.corrected_errors);
+        },
+        Command::Capacity { input_file, bits_per_channel, quality, error_correction } => {
+            let image_bytes = fs::read(InputPath::path(&input_file).path()).with_context(|| format!("Failed reading file: {}", &input_file.path()))?;
+
+            let capacity = steg.call_capacity_with_options(&mut store, &image_bytes, &EmbedOptions { bits_per_channel, quality, key: None, password: None, recipients: Vec::new(), signing_key: None, error_correction, decoy: None })?.map_err(StegoFailure)?;
+
+            println!("{}", capacity);
+        },
+        Command::Keygen { output_file, signing } => {
+            let (public_key, private_key) = if signing {
+                let keypair = steg.call_generate_signing_keypair(&mut store)?;
+
+                (keypair.public_key, keypair.signing_key)
+            } else {
+                let keypair = steg.call_generate_keypair(&mut store)?;
+
+                (keypair.public_key, keypair.identity)
+            };
+            let key_file = format!("# public key: {}\n{}\n", public_key, private_key);
+
+            if output_file.path().is_std() {
+                print!("{}", key_file);
+            } else {
+                fs::write(output_file.path().path(), key_file).with_context(|| format!("Failed writing file: {}", &output_file.path()))?;
+                println!("Public key: {}", public_key);
+            }
+        },
+        Command::Inspect { input_file } => {
             let image_bytes = fs::read(InputPath::path(&input_file).path()).with_context(|| format!("Failed reading file: {}", &input_file.path()))?;
-            let secret_decoded = match file_type.as_str() {
-                "bmp" => steg.call_decode_secret_from_bmp(&mut store, &image_bytes)?,
-                "jpg" | "jpeg" => steg.call_decode_secret_from_jpeg(&mut store, &image_bytes)?,
-                _ => panic!("unsupported file type: {}", file_type),
+            // the component sniffs the format itself, the extension doesn't matter here
+            let report = steg.call_probe(&mut store, &image_bytes)?.map_err(StegoFailure)?;
+
+            let format = match report.format {
+                CarrierFormat::Bmp => "bmp",
+                CarrierFormat::Jpeg => "jpeg",
+                CarrierFormat::Png => "png",
             };
+            println!("format: {}", format);
+            println!("dimensions: {}x{}", report.width, report.height);
+            println!("capacity: {} bytes", report.capacity);
 
-            println!("{}", secret_decoded);
+            match report.header {
+                Some(header) => println!("payload: {} bytes, version {}, flags: {}, bits per channel: {}", header.length, header.version, describe_flags(header.flags), header.bits_per_channel),
+                None => println!("payload: none"),
+            }
         }
     }
 
     Ok(())
 }
+
+fn read_image(input_file: &InputPath) -> anyhow::Result<Vec<u8>> {
+    fs::read(input_file.path().path()).with_context(|| format!("Failed reading file: {}", &input_file.path()))
+}
+
+/// Private keys in a file written by keygen, every line that isn't blank or a `#` comment.
+fn read_keys(key_file: &InputPath) -> anyhow::Result<Vec<String>> {
+    let content = fs::read_to_string(key_file.path().path()).with_context(|| format!("Failed reading file: {}", &key_file.path()))?;
+
+    Ok(content.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')).map(str::to_string).collect())
+}
+
+/// File to embed with its name, and the given media type or one guessed from its extension.
+fn read_file_payload(file: &InputPath, media_type: Option<String>) -> anyhow::Result<FilePayload> {
+    let content = fs::read(file.path().path()).with_context(|| format!("Failed reading file: {}", &file.path()))?;
+    let name = file.path().path().file_name().and_then(|name| name.to_str()).with_context(|| format!("Not a file name: {}", &file.path()))?;
+    let media_type = media_type.unwrap_or_else(|| mime_guess::from_path(name).first_or_octet_stream().essence_str().to_string());
+
+    Ok(FilePayload { name: name.to_string(), media_type, content })
+}
+
+/// Writes extracted files under their original names in `dir`, the current directory if not set.
+fn extract_files(dir: Option<&Path>, files: &[FilePayload]) -> anyhow::Result<()> {
+    let dir = dir.unwrap_or(Path::new("."));
+    fs::create_dir_all(dir).with_context(|| format!("Failed creating directory: {}", dir.display()))?;
+
+    // the component only hands over bare names, no file can land outside the directory
+    let paths: Vec<PathBuf> = files.iter().map(|file| dir.join(&file.name)).collect();
+    ensure_none_exist(&paths)?;
+
+    paths.iter().zip(files).try_for_each(|(path, file)| write_file(path, file))
+}
+
+/// Fails listing every one of `paths` that already exists, so nothing is written when any of them would be overwritten.
+fn ensure_none_exist(paths: &[PathBuf]) -> anyhow::Result<()> {
+    let existing: Vec<String> = paths.iter().filter(|path| path.exists()).map(|path| path.display().to_string()).collect();
+    anyhow::ensure!(existing.is_empty(), "Files already exist, nothing was written so they aren't overwritten: {}", existing.join(", "));
+
+    Ok(())
+}
+
+/// Writes an extracted file to `path`, which must not exist yet as the image chose its name.
+fn write_file(path: &Path, file: &FilePayload) -> anyhow::Result<()> {
+    create_new_file(path, &file.content)?;
+    eprintln!("Wrote {} ({}, {} bytes)", path.display(), file.media_type, file.content.len());
+
+    Ok(())
+}
+
+/// Writes `content` to a new file at `path`, failing instead of overwriting one already there.
+fn create_new_file(path: &Path, content: &[u8]) -> anyhow::Result<()> {
+    let mut output = match OpenOptions::new().write(true).create_new(true).open(path) {
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => anyhow::bail!("File already exists, not overwriting it: {}", path.display()),
+        output => output.with_context(|| format!("Failed writing file: {}", path.display()))?,
+    };
+
+    output.write_all(content).with_context(|| format!("Failed writing file: {}", path.display()))
+}
+
+/// Tells on stderr who signed the secret and what error correction repaired, so it doesn't mix
+/// with the secret on stdout.
+fn report_decoded(signature: &SignatureStatus, corrected_errors: u32) {
+    match signature {
+        SignatureStatus::Unsigned => {},
+        SignatureStatus::Valid(signer) => eprintln!("Good signature from {}", signer),
+        SignatureStatus::Invalid(signer) => eprintln!("Warning: bad signature claiming to be from {}, the secret was tampered with", signer),
+    }
+
+    if corrected_errors > 0 {
+        eprintln!("Corrected {} damaged bytes", corrected_errors);
+    }
+}
+
+fn describe_flags(flags: PayloadFlags) -> String {
+    let names: Vec<&str> = [
+        (PayloadFlags::COMPRESSED, "compressed"),
+        (PayloadFlags::ENCRYPTED, "encrypted"),
+        (PayloadFlags::BINARY, "binary"),
+        (PayloadFlags::RECIPIENTS, "recipients"),
+        (PayloadFlags::SIGNED, "signed"),
+        (PayloadFlags::ERROR_CORRECTED, "error corrected"),
+        (PayloadFlags::FILE, "file"),
+        (PayloadFlags::ARCHIVE, "archive"),
+        (PayloadFlags::SPLIT, "split"),
+        (PayloadFlags::SHARED, "shared"),
+    ].into_iter().filter(|(flag, _)| flags.contains(*flag)).map(|(_, name)| name).collect();
+
+    if names.is_empty() { "none".to_string() } else { names.join(", ") }
+}
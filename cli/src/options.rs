@@ -1,5 +1,6 @@
-use clap::{Parser, Subcommand};
+use clap::{ArgGroup, Parser, Subcommand};
 use clio::{InputPath, OutputPath};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -11,22 +12,117 @@ pub(crate) struct Cli {
 #[derive(Subcommand, Debug)]
 pub(crate) enum Command {
     /// Embeds a secret into an image
+    #[command(group(ArgGroup::new("payload").required(true).args(["secret", "secret_file", "file", "add"])))]
     Encode {
         /// Secret to be embeded into the image
         #[arg(short, long)]
-        secret: String,
-        /// Path to the image. Must be a 24bit depth BMP file.
+        secret: Option<String>,
+        /// File whose raw bytes are embeded into the image instead of a text secret.
+        #[arg(long, value_parser)]
+        secret_file: Option<InputPath>,
+        /// File embeded whole, with its name and media type, so decode writes it back as it was.
         #[arg(short, long, value_parser)]
-        input_file: InputPath,
+        file: Option<InputPath>,
+        /// Media type stored with --file, guessed from its extension if not set.
+        #[arg(long, requires = "file", value_name = "MIME_TYPE")]
+        media_type: Option<String>,
+        /// File bundled into an archive with its name and media type. Repeat it to add several.
+        #[arg(long, value_parser, value_name = "FILE")]
+        add: Vec<InputPath>,
+        /// Path to the image. Must be a 24 bit, 32 bit or grayscale BMP, a JPEG or an 8 bit PNG file. Repeat it with --split to spread the secret across several images.
+        #[arg(short, long = "input-file", value_parser, required = true)]
+        input_files: Vec<InputPath>,
 
         /// File path for the new encoded image.
         #[arg(short, long, value_parser, default_value="-")]
-        output_file: OutputPath
+        output_file: OutputPath,
+        /// Spreads the secret across every input image and writes them to this folder under their own names.
+        #[arg(long, value_name = "DIR", conflicts_with = "output_file")]
+        split: Option<PathBuf>,
+        /// With --split, shares the secret instead, so any K of the images recover it and fewer reveal nothing of it. Every image must hold the whole secret.
+        #[arg(long, value_name = "K", requires = "split")]
+        threshold: Option<u8>,
+        /// Least significant bits used in every color channel of BMP and PNG images, from 1 to 4. More bits hold more secret but are easier to spot.
+        #[arg(short, long, default_value_t = 1)]
+        bits_per_channel: u8,
+        /// Quality JPEG images are re-encoded at with the standard quantization tables, from 1 to 100. Without it baseline JPEGs keep their own tables.
+        #[arg(short, long)]
+        quality: Option<u8>,
+        /// Stego key scattering the secret over the image. The same key is needed to decode it.
+        #[arg(short, long)]
+        key: Option<String>,
+        /// Password the secret is encrypted with before embedding it. Prefer the environment variable, arguments are visible to other processes.
+        #[arg(short, long, env = "STEGO_PASSWORD", hide_env_values = true, conflicts_with = "recipients")]
+        password: Option<String>,
+        /// Public key the secret is encrypted to, as printed by keygen. Repeat it for several recipients.
+        #[arg(short, long = "recipient", value_name = "PUBLIC_KEY")]
+        recipients: Vec<String>,
+        /// File written by keygen --signing holding the key the secret is signed with.
+        #[arg(long = "signing-key", value_parser, value_name = "SIGNING_KEY_FILE")]
+        signing_key_file: Option<InputPath>,
+        /// Reed-Solomon parity bytes added to every 255 byte block, up to 128. A block survives as many damaged bytes as half its parity, and the header in front of the blocks is stored three times.
+        #[arg(short, long, value_name = "PARITY_BYTES", default_value_t = 0)]
+        error_correction: u8,
+        /// Harmless message hidden along the secret, which --decoy-password extracts instead. Nothing in the image tells the secret is there too.
+        #[arg(long, value_name = "MESSAGE", requires_all = ["decoy_password", "password"])]
+        decoy: Option<String>,
+        /// Password that extracts the decoy message, it must differ from the one of the secret.
+        #[arg(long, env = "STEGO_DECOY_PASSWORD", hide_env_values = true)]
+        decoy_password: Option<String>
     },
     /// Gets the secret out of a previously encoded image
     Decode {
-        /// Path to the encoded image.
+        /// Path to the encoded image. Repeat it to join a secret split across several images, in any order.
+        #[arg(short, long = "input-file", value_parser, required = true)]
+        input_files: Vec<InputPath>,
+
+        /// File where the raw bytes of the secret are written. If not set, text is printed and an embeded file is written under its original name.
+        #[arg(short, long, value_parser, conflicts_with_all = ["extract_dir", "list"])]
+        output_file: Option<OutputPath>,
+        /// Folder where an embeded file or the files of an archive are written under their original names, instead of the current one.
+        #[arg(long, value_name = "DIR", conflicts_with = "list")]
+        extract_dir: Option<PathBuf>,
+        /// Lists the name, media type and size of an embeded file or the files of an archive without writing them.
+        #[arg(long)]
+        list: bool,
+        /// Stego key the secret was encoded with.
+        #[arg(short, long)]
+        key: Option<String>,
+        /// Password the secret was encrypted with.
+        #[arg(short, long, env = "STEGO_PASSWORD", hide_env_values = true)]
+        password: Option<String>,
+        /// File written by keygen holding the identity of a recipient. Repeat it to try several.
+        #[arg(long = "identity", value_parser, value_name = "IDENTITY_FILE")]
+        identities: Vec<InputPath>
+    },
+    /// Prints how many bytes of secret an image can hold
+    Capacity {
+        /// Path to the image.
+        #[arg(short, long, value_parser)]
+        input_file: InputPath,
+        /// Least significant bits used in every color channel of BMP and PNG images, from 1 to 4.
+        #[arg(short, long, default_value_t = 1)]
+        bits_per_channel: u8,
+        /// Quality JPEG images would be re-encoded at, from 1 to 100.
+        #[arg(short, long)]
+        quality: Option<u8>,
+        /// Reed-Solomon parity bytes added to every 255 byte block, up to 128.
+        #[arg(short, long, value_name = "PARITY_BYTES", default_value_t = 0)]
+        error_correction: u8
+    },
+    /// Generates a key pair to encrypt secrets to with --recipient or to sign them with --signing-key
+    Keygen {
+        /// File the identity or signing key is written to, keep it private. The public key is printed.
+        #[arg(short, long, value_parser, default_value="-")]
+        output_file: OutputPath,
+        /// Generates a key pair to sign secrets with instead of one to encrypt them to.
+        #[arg(long)]
+        signing: bool
+    },
+    /// Tells whether an image carries a secret without extracting it
+    Inspect {
+        /// Path to the image.
         #[arg(short, long, value_parser)]
-        input_file: clio::InputPath,
+        input_file: InputPath
     }
 }
@@ -1,5 +1,6 @@
 use assert_cmd::Command;
-use assert_fs::NamedTempFile;
+use assert_fs::{NamedTempFile, TempDir};
+use assert_fs::prelude::*;
 
 #[test]
 fn encode_valid_bmp_returns_image() {
@@ -51,3 +52,678 @@ fn decode_unencoded_bmp_panics() {
 
     assert!(res.is_err());
 }
+
+#[test]
+fn decode_unencoded_bmp_reports_missing_secret() {
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "decode",
+        "-i",
+        "tests/data/test.bmp",
+    ])
+    .assert()
+    .failure()
+    .code(6)
+    .stderr(predicates::str::contains("doesn't contain a hidden secret"));
+}
+
+#[test]
+fn encode_and_decode_secret_file_round_trips_bytes() {
+    let secret_file = NamedTempFile::new("secret.bin").unwrap();
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let decoded_file = NamedTempFile::new("decoded.bin").unwrap();
+    let secret = [0x00u8, 0xFF, 0xFE, 0x80, 0x7F];
+    std::fs::write(&secret_file, secret).unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "--secret-file",
+        secret_file.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "-o",
+        decoded_file.to_str().unwrap(),
+    ]).assert().success();
+
+    let decoded = std::fs::read(decoded_file).expect("failed reading the decoded secret");
+    assert_eq!(decoded, secret);
+}
+
+#[test]
+fn capacity_of_bmp_prints_usable_bytes() {
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "capacity",
+        "-i",
+        "tests/data/test.bmp",
+    ])
+    .assert()
+    .success()
+    .stdout("216\n");
+}
+
+#[test]
+fn inspect_encoded_bmp_prints_payload_header() {
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "inspect",
+        "-i",
+        "tests/data/test-encoded.bmp",
+    ])
+    .assert()
+    .success()
+    .stdout("format: bmp\ndimensions: 25x25\ncapacity: 216 bytes\npayload: 3 bytes, version 2, flags: none, bits per channel: 1\n");
+}
+
+#[test]
+fn inspect_plain_bmp_prints_no_payload() {
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "inspect",
+        "-i",
+        "tests/data/test.bmp",
+    ])
+    .assert()
+    .success()
+    .stdout(predicates::str::ends_with("payload: none\n"));
+}
+
+#[test]
+fn encode_and_decode_png_round_trips_secret() {
+    let encoded_file = NamedTempFile::new("encoded.png").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.png",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+}
+
+#[test]
+fn capacity_grows_with_bits_per_channel() {
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "capacity",
+        "-i",
+        "tests/data/test.bmp",
+        "--bits-per-channel",
+        "4",
+    ])
+    .assert()
+    .success()
+    .stdout("865\n");
+}
+
+#[test]
+fn encode_with_too_many_bits_per_channel_fails_with_invalid_option_code() {
+    let output_file = NamedTempFile::new("output.bmp").unwrap();
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        output_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--bits-per-channel",
+        "5",
+    ])
+    .assert()
+    .failure()
+    .code(9);
+}
+
+#[test]
+fn encode_and_decode_jpeg_at_a_quality_round_trips_secret() {
+    let encoded_file = NamedTempFile::new("encoded.jpeg").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.jpeg",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--quality",
+        "60",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+}
+
+#[test]
+fn encode_with_jpeg_quality_out_of_range_fails_with_invalid_option_code() {
+    let output_file = NamedTempFile::new("output.jpeg").unwrap();
+    let mut cmd = Command::cargo_bin("cli").unwrap();
+    cmd.args(&[
+        "encode",
+        "-i",
+        "tests/data/test.jpeg",
+        "-o",
+        output_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--quality",
+        "101",
+    ])
+    .assert()
+    .failure()
+    .code(9);
+}
+
+#[test]
+fn encode_and_decode_with_key_round_trips_secret() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--key",
+        "correct horse",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--key",
+        "correct horse",
+    ]).assert().success().stdout("foo\n");
+
+    // without the key the secret can't be found
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().failure().code(6);
+}
+
+#[test]
+fn encode_and_decode_with_password_round_trips_secret() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+    ]).env("STEGO_PASSWORD", "hunter2").assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--password",
+        "hunter2",
+    ]).assert().success().stdout("foo\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().failure().code(10);
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--password",
+        "hunter3",
+    ]).assert().failure().code(11);
+}
+
+#[test]
+fn keygen_identity_decodes_secret_encoded_to_its_public_key() {
+    let identity_file = NamedTempFile::new("identity.txt").unwrap();
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    let output = Command::cargo_bin("cli").unwrap().args(&[
+        "keygen",
+        "-o",
+        identity_file.to_str().unwrap(),
+    ]).output().unwrap();
+    let stdout = std::str::from_utf8(&output.stdout).unwrap();
+    let public_key = stdout.trim_end().strip_prefix("Public key: ").expect("keygen didn't print the public key");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--recipient",
+        public_key,
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--identity",
+        identity_file.to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().failure().code(12);
+}
+
+#[test]
+fn decode_secret_signed_with_keygen_signing_key_reports_the_signer() {
+    let signing_key_file = NamedTempFile::new("signing-key.txt").unwrap();
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    let output = Command::cargo_bin("cli").unwrap().args(&[
+        "keygen",
+        "--signing",
+        "-o",
+        signing_key_file.to_str().unwrap(),
+    ]).output().unwrap();
+    let stdout = std::str::from_utf8(&output.stdout).unwrap();
+    let public_key = stdout.trim_end().strip_prefix("Public key: ").expect("keygen didn't print the public key");
+    assert!(public_key.starts_with("wstg-sig-"));
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--signing-key",
+        signing_key_file.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout("foo\n").stderr(format!("Good signature from {}\n", public_key));
+}
+
+#[test]
+fn encode_compressible_secret_longer_than_the_capacity_round_trips() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let secret = "foo bar ".repeat(100);
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        &secret,
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "inspect",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout(predicates::str::contains("flags: compressed,"));
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout(format!("{secret}\n"));
+}
+
+#[test]
+fn encode_with_error_correction_round_trips_secret() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--error-correction",
+        "16",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "inspect",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout(predicates::str::contains("payload: 22 bytes, version 4, flags: error corrected,"));
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+}
+
+#[test]
+fn encode_file_is_decoded_under_its_original_name() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let secret_file = NamedTempFile::new("notes.txt").unwrap();
+    secret_file.write_str("foo").unwrap();
+    let output_dir = TempDir::new().unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "--file",
+        secret_file.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "inspect",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout(predicates::str::contains("flags: file,"));
+
+    Command::cargo_bin("cli").unwrap().current_dir(output_dir.path()).args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().success().stdout("").stderr("Wrote notes.txt (text/plain, 3 bytes)\n");
+
+    output_dir.child("notes.txt").assert("foo");
+}
+
+#[test]
+fn decode_file_never_overwrites_an_existing_one() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let secret_file = NamedTempFile::new("notes.txt").unwrap();
+    secret_file.write_str("foo").unwrap();
+    let output_dir = TempDir::new().unwrap();
+    output_dir.child("notes.txt").write_str("mine").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "--file",
+        secret_file.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().current_dir(output_dir.path()).args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+    ]).assert().failure().stderr(predicates::str::contains("File already exists, not overwriting it"));
+
+    output_dir.child("notes.txt").assert("mine");
+}
+
+#[test]
+fn encode_archive_is_listed_and_extracted() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let note = NamedTempFile::new("note.txt").unwrap();
+    note.write_str("foo").unwrap();
+    let attachment = NamedTempFile::new("attachment.bin").unwrap();
+    let attachment_bytes: &'static [u8] = &[0, 159, 146, 150];
+    attachment.write_binary(attachment_bytes).unwrap();
+    let output_dir = TempDir::new().unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "--add",
+        note.to_str().unwrap(),
+        "--add",
+        attachment.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--list",
+    ]).assert().success().stdout("note.txt (text/plain, 3 bytes)\nattachment.bin (application/octet-stream, 4 bytes)\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--extract-dir",
+        output_dir.child("bundle").to_str().unwrap(),
+    ]).assert().success();
+
+    output_dir.child("bundle/note.txt").assert("foo");
+    output_dir.child("bundle/attachment.bin").assert(attachment_bytes);
+}
+
+#[test]
+fn extract_archive_over_an_existing_file_writes_nothing() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+    let note = NamedTempFile::new("note.txt").unwrap();
+    note.write_str("foo").unwrap();
+    let attachment = NamedTempFile::new("attachment.bin").unwrap();
+    attachment.write_binary(&[0, 159, 146, 150]).unwrap();
+    let output_dir = TempDir::new().unwrap();
+    output_dir.child("attachment.bin").write_str("mine").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "--add",
+        note.to_str().unwrap(),
+        "--add",
+        attachment.to_str().unwrap(),
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--extract-dir",
+        output_dir.to_str().unwrap(),
+    ]).assert().failure().stderr(predicates::str::contains("attachment.bin"));
+
+    // the file before the collision isn't written either
+    output_dir.child("note.txt").assert(predicates::path::missing());
+    output_dir.child("attachment.bin").assert("mine");
+}
+
+#[test]
+fn encode_split_is_joined_back_by_decode() {
+    let output_dir = TempDir::new().unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-i",
+        "tests/data/test.png",
+        "--split",
+        output_dir.to_str().unwrap(),
+        "-s",
+        "foo",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "inspect",
+        "-i",
+        output_dir.child("test.png").to_str().unwrap(),
+    ]).assert().success().stdout(predicates::str::contains("version 3, flags: split,"));
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        output_dir.child("test.png").to_str().unwrap(),
+        "-i",
+        output_dir.child("test.bmp").to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        output_dir.child("test.bmp").to_str().unwrap(),
+    ]).assert().failure().code(14).stderr(predicates::str::contains("missing chunks 2 of 2"));
+}
+
+#[test]
+fn encode_split_never_overwrites_the_cover_images() {
+    let cover_dir = TempDir::new().unwrap();
+    cover_dir.copy_from("tests/data", &["test.bmp", "test.png"]).unwrap();
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").unwrap();
+    let png_bytes = std::fs::read("tests/data/test.png").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        cover_dir.child("test.bmp").to_str().unwrap(),
+        "-i",
+        cover_dir.child("test.png").to_str().unwrap(),
+        "--split",
+        cover_dir.to_str().unwrap(),
+        "-s",
+        "foo",
+    ]).assert().failure().stderr(predicates::str::contains("Files already exist, nothing was written"));
+
+    assert_eq!(std::fs::read(cover_dir.child("test.bmp").path()).unwrap(), bmp_bytes);
+    assert_eq!(std::fs::read(cover_dir.child("test.png").path()).unwrap(), png_bytes);
+}
+
+#[test]
+fn encode_shared_is_recovered_by_any_two_images() {
+    let output_dir = TempDir::new().unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-i",
+        "tests/data/test.png",
+        "-i",
+        "tests/data/test-encoded.bmp",
+        "--split",
+        output_dir.to_str().unwrap(),
+        "--threshold",
+        "2",
+        "-s",
+        "foo",
+    ]).assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        output_dir.child("test-encoded.bmp").to_str().unwrap(),
+        "-i",
+        output_dir.child("test.bmp").to_str().unwrap(),
+    ]).assert().success().stdout("foo\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        output_dir.child("test.png").to_str().unwrap(),
+    ]).assert().failure().code(14).stderr(predicates::str::contains("1 of the 2 shares needed"));
+}
+
+#[test]
+fn encode_with_decoy_gives_each_password_its_own_message() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--decoy",
+        "bar",
+    ]).env("STEGO_PASSWORD", "hunter2").env("STEGO_DECOY_PASSWORD", "opensesame").assert().success();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--password",
+        "opensesame",
+    ]).assert().success().stdout("bar\n");
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "decode",
+        "-i",
+        encoded_file.to_str().unwrap(),
+        "--password",
+        "hunter2",
+    ]).assert().success().stdout("foo\n");
+}
+
+#[test]
+fn encode_with_decoy_password_but_no_decoy_fails() {
+    let encoded_file = NamedTempFile::new("encoded.bmp").unwrap();
+
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+        "--decoy-password",
+        "opensesame",
+    ]).env("STEGO_PASSWORD", "hunter2").assert().failure().code(2).stderr(predicates::str::contains("--decoy-password needs --decoy"));
+
+    encoded_file.assert(predicates::path::missing());
+
+    // the environment variable alone is no mistake
+    Command::cargo_bin("cli").unwrap().args(&[
+        "encode",
+        "-i",
+        "tests/data/test.bmp",
+        "-o",
+        encoded_file.to_str().unwrap(),
+        "-s",
+        "foo",
+    ]).env("STEGO_PASSWORD", "hunter2").env("STEGO_DECOY_PASSWORD", "opensesame").assert().success();
+}
@@ -1,6 +1,400 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
+/// Reasons why embedding or extracting a secret can fail.
+#[derive(Clone)]
+pub enum StegoError {
+    /// The carrier couldn't be read or written as a supported image format.
+    UnsupportedFormat(_rt::String),
+    /// The image was decoded but its pixel layout can't carry a secret.
+    WrongPixelLayout(_rt::String),
+    /// The secret doesn't fit into the carrier.
+    CapacityExceeded,
+    /// The carrier doesn't hold any hidden secret.
+    NoPayloadFound,
+    /// A hidden secret was found but it is damaged or truncated.
+    CorruptPayload(_rt::String),
+    /// The hidden secret isn't valid UTF-8 text.
+    InvalidUtf8,
+    /// An embedding option is out of range.
+    InvalidOption(_rt::String),
+    /// The hidden secret is encrypted and no password was given.
+    PasswordRequired,
+    /// The password doesn't decrypt the hidden secret.
+    WrongPassword,
+    /// The hidden secret is encrypted to recipients and no identity was given.
+    IdentityRequired,
+    /// None of the identities given is a recipient of the hidden secret.
+    NotARecipient,
+    /// The images given don't hold every chunk of a split payload or enough shares of a shared one, or hold parts of
+    /// different ones.
+    IncompleteSplit(_rt::String),
+}
+impl ::core::fmt::Debug for StegoError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            StegoError::UnsupportedFormat(e) => {
+                f.debug_tuple("StegoError::UnsupportedFormat").field(e).finish()
+            }
+            StegoError::WrongPixelLayout(e) => {
+                f.debug_tuple("StegoError::WrongPixelLayout").field(e).finish()
+            }
+            StegoError::CapacityExceeded => {
+                f.debug_tuple("StegoError::CapacityExceeded").finish()
+            }
+            StegoError::NoPayloadFound => {
+                f.debug_tuple("StegoError::NoPayloadFound").finish()
+            }
+            StegoError::CorruptPayload(e) => {
+                f.debug_tuple("StegoError::CorruptPayload").field(e).finish()
+            }
+            StegoError::InvalidUtf8 => f.debug_tuple("StegoError::InvalidUtf8").finish(),
+            StegoError::InvalidOption(e) => {
+                f.debug_tuple("StegoError::InvalidOption").field(e).finish()
+            }
+            StegoError::PasswordRequired => {
+                f.debug_tuple("StegoError::PasswordRequired").finish()
+            }
+            StegoError::WrongPassword => {
+                f.debug_tuple("StegoError::WrongPassword").finish()
+            }
+            StegoError::IdentityRequired => {
+                f.debug_tuple("StegoError::IdentityRequired").finish()
+            }
+            StegoError::NotARecipient => {
+                f.debug_tuple("StegoError::NotARecipient").finish()
+            }
+            StegoError::IncompleteSplit(e) => {
+                f.debug_tuple("StegoError::IncompleteSplit").field(e).finish()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for StegoError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl std::error::Error for StegoError {}
+/// Harmless message to give away under coercion, hidden in the same image as the real secret.
+#[derive(Clone)]
+pub struct Decoy {
+    /// Innocuous text its password extracts.
+    pub message: _rt::String,
+    /// Password that extracts the message, it must differ from the one of the real secret.
+    pub password: _rt::String,
+}
+impl ::core::fmt::Debug for Decoy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Decoy")
+            .field("message", &self.message)
+            .field("password", &self.password)
+            .finish()
+    }
+}
+/// Knobs for embedding a secret, the exports without options use the defaults.
+#[derive(Clone)]
+pub struct EmbedOptions {
+    /// Least significant bits taken from every color sample of BMP and PNG images, 1 to 4. JPEG ignores it.
+    pub bits_per_channel: u8,
+    /// JPEG quality, 1 to 100, the standard quantization tables are scaled by. With it every JPEG is re-encoded at that
+    /// quality, without it baseline JPEGs keep their own tables and any other is re-encoded at quality 80.
+    pub quality: Option<u8>,
+    /// Stego key picking the pixels or JPEG blocks that hold the secret, which are otherwise the first ones.
+    /// The same key is needed to extract it.
+    pub key: Option<_rt::String>,
+    /// Encrypts the secret with a key derived from this password before hiding it.
+    pub password: Option<_rt::String>,
+    /// Public keys the secret is encrypted to before hiding it, only their identities can extract it.
+    /// Can't be combined with a password.
+    pub recipients: _rt::Vec<_rt::String>,
+    /// Signs the secret so receivers can tell who embedded it.
+    pub signing_key: Option<_rt::String>,
+    /// Reed–Solomon parity bytes added to every block of up to 255 bytes, 0 to 128, 0 disables it.
+    /// A block survives as many damaged bytes as half its parity. The header in front of the blocks is stored three
+    /// times instead, so damage to one copy is outvoted.
+    pub error_correction: u8,
+    /// Hides the secret, which must be encrypted with a password, behind this decoy. Nothing in the image tells there is
+    /// more than the decoy, only the password of the secret finds it. Not supported with split or shared payloads.
+    pub decoy: Option<Decoy>,
+}
+impl ::core::fmt::Debug for EmbedOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("EmbedOptions")
+            .field("bits-per-channel", &self.bits_per_channel)
+            .field("quality", &self.quality)
+            .field("key", &self.key)
+            .field("password", &self.password)
+            .field("recipients", &self.recipients)
+            .field("signing-key", &self.signing_key)
+            .field("error-correction", &self.error_correction)
+            .field("decoy", &self.decoy)
+            .finish()
+    }
+}
+/// Knobs for extracting a secret, they must match the ones it was embedded with.
+#[derive(Clone)]
+pub struct ExtractOptions {
+    /// Stego key the secret was embedded with, if any.
+    pub key: Option<_rt::String>,
+    /// Password the secret was encrypted with, if any.
+    pub password: Option<_rt::String>,
+    /// Identities tried on a secret encrypted to recipients.
+    pub identities: _rt::Vec<_rt::String>,
+}
+impl ::core::fmt::Debug for ExtractOptions {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ExtractOptions")
+            .field("key", &self.key)
+            .field("password", &self.password)
+            .field("identities", &self.identities)
+            .finish()
+    }
+}
+/// X25519 key pair for encrypting secrets to a recipient.
+#[derive(Clone)]
+pub struct Keypair {
+    /// Shared with whoever encrypts, starts with `wstg-pub-`.
+    pub public_key: _rt::String,
+    /// Kept private to decrypt, starts with `WSTG-SECRET-KEY-`.
+    pub identity: _rt::String,
+}
+impl ::core::fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Keypair")
+            .field("public-key", &self.public_key)
+            .field("identity", &self.identity)
+            .finish()
+    }
+}
+/// Ed25519 key pair for signing secrets.
+#[derive(Clone)]
+pub struct SigningKeypair {
+    /// Shared with receivers to recognize the signer, starts with `wstg-sig-`.
+    pub public_key: _rt::String,
+    /// Kept private to sign, starts with `WSTG-SIGNING-KEY-`.
+    pub signing_key: _rt::String,
+}
+impl ::core::fmt::Debug for SigningKeypair {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("SigningKeypair")
+            .field("public-key", &self.public_key)
+            .field("signing-key", &self.signing_key)
+            .finish()
+    }
+}
+/// Whether an extracted secret was signed and by whom.
+#[derive(Clone)]
+pub enum SignatureStatus {
+    Unsigned,
+    /// Signed by the holder of this public key.
+    Valid(_rt::String),
+    /// Claims to be signed by this public key but the signature doesn't match, it was tampered with.
+    Invalid(_rt::String),
+}
+impl ::core::fmt::Debug for SignatureStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            SignatureStatus::Unsigned => {
+                f.debug_tuple("SignatureStatus::Unsigned").finish()
+            }
+            SignatureStatus::Valid(e) => {
+                f.debug_tuple("SignatureStatus::Valid").field(e).finish()
+            }
+            SignatureStatus::Invalid(e) => {
+                f.debug_tuple("SignatureStatus::Invalid").field(e).finish()
+            }
+        }
+    }
+}
+/// Text secret extracted with its signature status.
+#[derive(Clone)]
+pub struct DecodedSecret {
+    pub secret: _rt::String,
+    pub signature: SignatureStatus,
+    /// Damaged bytes repaired by error correction.
+    pub corrected_errors: u32,
+}
+impl ::core::fmt::Debug for DecodedSecret {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DecodedSecret")
+            .field("secret", &self.secret)
+            .field("signature", &self.signature)
+            .field("corrected-errors", &self.corrected_errors)
+            .finish()
+    }
+}
+/// Binary payload extracted with its signature status.
+#[derive(Clone)]
+pub struct DecodedBytes {
+    pub payload: _rt::Vec<u8>,
+    pub signature: SignatureStatus,
+    /// Damaged bytes repaired by error correction.
+    pub corrected_errors: u32,
+}
+impl ::core::fmt::Debug for DecodedBytes {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DecodedBytes")
+            .field("payload", &self.payload)
+            .field("signature", &self.signature)
+            .field("corrected-errors", &self.corrected_errors)
+            .finish()
+    }
+}
+/// File embedded whole, with what's needed to write it back.
+#[derive(Clone)]
+pub struct FilePayload {
+    /// Original file name, without any folder.
+    pub name: _rt::String,
+    /// MIME type of the content, like `image/png`.
+    pub media_type: _rt::String,
+    pub content: _rt::Vec<u8>,
+}
+impl ::core::fmt::Debug for FilePayload {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FilePayload")
+            .field("name", &self.name)
+            .field("media-type", &self.media_type)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+/// What a payload holds, told by its header flags.
+#[derive(Clone)]
+pub enum HiddenContent {
+    Text(_rt::String),
+    Binary(_rt::Vec<u8>),
+    File(FilePayload),
+    /// Several files bundled together, in the order they were given.
+    Archive(_rt::Vec<FilePayload>),
+}
+impl ::core::fmt::Debug for HiddenContent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            HiddenContent::Text(e) => {
+                f.debug_tuple("HiddenContent::Text").field(e).finish()
+            }
+            HiddenContent::Binary(e) => {
+                f.debug_tuple("HiddenContent::Binary").field(e).finish()
+            }
+            HiddenContent::File(e) => {
+                f.debug_tuple("HiddenContent::File").field(e).finish()
+            }
+            HiddenContent::Archive(e) => {
+                f.debug_tuple("HiddenContent::Archive").field(e).finish()
+            }
+        }
+    }
+}
+/// Payload extracted as whatever it was embedded as, with its signature status.
+#[derive(Clone)]
+pub struct DecodedPayload {
+    pub content: HiddenContent,
+    pub signature: SignatureStatus,
+    /// Damaged bytes repaired by error correction.
+    pub corrected_errors: u32,
+}
+impl ::core::fmt::Debug for DecodedPayload {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DecodedPayload")
+            .field("content", &self.content)
+            .field("signature", &self.signature)
+            .field("corrected-errors", &self.corrected_errors)
+            .finish()
+    }
+}
+/// Image formats that can carry a secret.
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CarrierFormat {
+    Bmp,
+    Jpeg,
+    Png,
+}
+impl ::core::fmt::Debug for CarrierFormat {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            CarrierFormat::Bmp => f.debug_tuple("CarrierFormat::Bmp").finish(),
+            CarrierFormat::Jpeg => f.debug_tuple("CarrierFormat::Jpeg").finish(),
+            CarrierFormat::Png => f.debug_tuple("CarrierFormat::Png").finish(),
+        }
+    }
+}
+impl CarrierFormat {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> CarrierFormat {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+        match val {
+            0 => CarrierFormat::Bmp,
+            1 => CarrierFormat::Jpeg,
+            2 => CarrierFormat::Png,
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+wit_bindgen_rt::bitflags::bitflags! {
+    #[doc = " Features of a hidden payload, stored in its header."] #[derive(PartialEq,
+    Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)] pub struct PayloadFlags : u16 { #[doc
+    = " DEFLATE compressed, done whenever it makes the payload smaller."] const
+    COMPRESSED = 1 << 0; const ENCRYPTED = 1 << 1; const BINARY = 1 << 2; #[doc =
+    " Encrypted to recipient public keys."] const RECIPIENTS = 1 << 3; #[doc =
+    " Preceded by the signer's public key and signature."] const SIGNED = 1 << 4; #[doc =
+    " Split in Reed–Solomon blocks that repair damaged bytes."] const ERROR_CORRECTED =
+    1 << 5; #[doc = " A whole file with its name and media type."] const FILE = 1 << 6;
+    #[doc =
+    " Several files, a directory of their names, media types and sizes followed by their contents."]
+    const ARCHIVE = 1 << 7; #[doc =
+    " A chunk of a payload split across several images, preceded by its set ID, index and count."]
+    const SPLIT = 1 << 8; #[doc =
+    " A share of a payload, any threshold of which recover it, preceded by its set ID, number and threshold."]
+    const SHARED = 1 << 9; }
+}
+/// Header found in front of a hidden payload.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PayloadHeader {
+    pub version: u8,
+    pub flags: PayloadFlags,
+    /// Bits per channel of BMP and PNG payloads, 0 when it doesn't apply.
+    pub bits_per_channel: u8,
+    /// Payload size in bytes, header excluded.
+    pub length: u32,
+}
+impl ::core::fmt::Debug for PayloadHeader {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("PayloadHeader")
+            .field("version", &self.version)
+            .field("flags", &self.flags)
+            .field("bits-per-channel", &self.bits_per_channel)
+            .field("length", &self.length)
+            .finish()
+    }
+}
+/// What an image is and whether it carries a secret.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProbeReport {
+    pub format: CarrierFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Payload bytes the image can hold, compressible payloads can be longer.
+    pub capacity: u64,
+    /// Only set when a valid payload header was found.
+    pub header: Option<PayloadHeader>,
+}
+impl ::core::fmt::Debug for ProbeReport {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ProbeReport")
+            .field("format", &self.format)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("capacity", &self.capacity)
+            .field("header", &self.header)
+            .finish()
+    }
+}
 #[allow(unused_unsafe, clippy::all)]
 pub fn log(message: &str) -> () {
     unsafe {
@@ -13,140 +407,7728 @@ pub fn log(message: &str) -> () {
             #[link_name = "log"]
             fn wit_import1(_: *mut u8, _: usize);
         }
-        #[cfg(not(target_arch = "wasm32"))]
-        unsafe extern "C" fn wit_import1(_: *mut u8, _: usize) {
-            unreachable!()
+        #[cfg(not(target_arch = "wasm32"))]
+        unsafe extern "C" fn wit_import1(_: *mut u8, _: usize) {
+            unreachable!()
+        }
+        unsafe { wit_import1(ptr0.cast_mut(), len0) };
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_secret_into_bmp_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let result2 = T::encode_secret_into_bmp(
+        _rt::string_lift(bytes0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_secret_into_bmp<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_secret_from_bmp_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_secret_from_bmp(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_secret_from_bmp<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l3 {
+                0 => {
+                    let l4 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                1 => {
+                    let l6 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l8 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                5 => {}
+                6 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_bytes_into_bmp_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let len1 = arg3;
+    let result2 = T::encode_bytes_into_bmp(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_bytes_into_bmp<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_bytes_from_bmp_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_bytes_from_bmp(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_bytes_from_bmp<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_capacity_bmp_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::capacity_bmp(_rt::Vec::from_raw_parts(arg0.cast(), len0, len0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len3;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr3.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_capacity_bmp<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l6 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                5 => {}
+                6 => {
+                    let l8 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_secret_into_jpeg_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let result2 = T::encode_secret_into_jpeg(
+        _rt::string_lift(bytes0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_secret_into_jpeg<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_secret_from_jpeg_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_secret_from_jpeg(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_secret_from_jpeg<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l3 {
+                0 => {
+                    let l4 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                1 => {
+                    let l6 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l8 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                5 => {}
+                6 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_bytes_into_jpeg_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let len1 = arg3;
+    let result2 = T::encode_bytes_into_jpeg(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_bytes_into_jpeg<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_bytes_from_jpeg_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_bytes_from_jpeg(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_bytes_from_jpeg<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_capacity_jpeg_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::capacity_jpeg(_rt::Vec::from_raw_parts(arg0.cast(), len0, len0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len3;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr3.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_capacity_jpeg<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l6 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                5 => {}
+                6 => {
+                    let l8 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_secret_into_png_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let result2 = T::encode_secret_into_png(
+        _rt::string_lift(bytes0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_secret_into_png<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_secret_from_png_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_secret_from_png(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_secret_from_png<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l3 {
+                0 => {
+                    let l4 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                1 => {
+                    let l6 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l8 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                5 => {}
+                6 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_bytes_into_png_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let len1 = arg3;
+    let result2 = T::encode_bytes_into_png(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+            *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr3
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_bytes_into_png<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_bytes_from_png_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::decode_bytes_from_png(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+    );
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_bytes_from_png<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_capacity_png_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::capacity_png(_rt::Vec::from_raw_parts(arg0.cast(), len0, len0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len3;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr3.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr5.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_capacity_png<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l6 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                5 => {}
+                6 => {
+                    let l8 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_secret_with_options_cabi<T: Guest>(
+    arg0: *mut u8,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len2 = l1;
+    let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+    let l3 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l4 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len5 = l4;
+    let l6 = i32::from(*arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l7 = i32::from(
+        *arg0.add(1 + 4 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l9 = i32::from(*arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l13 = i32::from(*arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l17 = *arg0.add(11 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l18 = *arg0.add(12 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base22 = l17;
+    let len22 = l18;
+    let mut result22 = _rt::Vec::with_capacity(len22);
+    for i in 0..len22 {
+        let base = base22.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e22 = {
+            let l19 = *base.add(0).cast::<*mut u8>();
+            let l20 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len21 = l20;
+            let bytes21 = _rt::Vec::from_raw_parts(l19.cast(), len21, len21);
+            _rt::string_lift(bytes21)
+        };
+        result22.push(e22);
+    }
+    _rt::cabi_dealloc(
+        base22,
+        len22 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l23 = i32::from(
+        *arg0.add(13 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l27 = i32::from(
+        *arg0.add(16 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l28 = i32::from(
+        *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result35 = T::encode_secret_with_options(
+        _rt::string_lift(bytes2),
+        _rt::Vec::from_raw_parts(l3.cast(), len5, len5),
+        EmbedOptions {
+            bits_per_channel: l6 as u8,
+            quality: match l7 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l8 = i32::from(
+                            *arg0
+                                .add(2 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l8 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l9 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l10 = *arg0
+                            .add(6 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l11 = *arg0
+                            .add(7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len12 = l11;
+                        let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+                        _rt::string_lift(bytes12)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l13 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l14 = *arg0
+                            .add(9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l15 = *arg0
+                            .add(10 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        _rt::string_lift(bytes16)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result22,
+            signing_key: match l23 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l24 = *arg0
+                            .add(14 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l25 = *arg0
+                            .add(15 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len26 = l25;
+                        let bytes26 = _rt::Vec::from_raw_parts(l24.cast(), len26, len26);
+                        _rt::string_lift(bytes26)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l27 as u8,
+            decoy: match l28 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l29 = *arg0
+                            .add(18 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l30 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len31 = l30;
+                        let bytes31 = _rt::Vec::from_raw_parts(l29.cast(), len31, len31);
+                        let l32 = *arg0
+                            .add(20 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l33 = *arg0
+                            .add(21 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len34 = l33;
+                        let bytes34 = _rt::Vec::from_raw_parts(l32.cast(), len34, len34);
+                        Decoy {
+                            message: _rt::string_lift(bytes31),
+                            password: _rt::string_lift(bytes34),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        22 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr36 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result35 {
+        Ok(e) => {
+            *ptr36.add(0).cast::<u8>() = (0i32) as u8;
+            let vec37 = (e).into_boxed_slice();
+            let ptr37 = vec37.as_ptr().cast::<u8>();
+            let len37 = vec37.len();
+            ::core::mem::forget(vec37);
+            *ptr36.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len37;
+            *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr37
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr36.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                    let len38 = vec38.len();
+                    ::core::mem::forget(vec38);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len38;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr38.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                    let len39 = vec39.len();
+                    ::core::mem::forget(vec39);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len39;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr39.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                    let len40 = vec40.len();
+                    ::core::mem::forget(vec40);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len40;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr40.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec41 = (e.into_bytes()).into_boxed_slice();
+                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                    let len41 = vec41.len();
+                    ::core::mem::forget(vec41);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len41;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr41.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec42 = (e.into_bytes()).into_boxed_slice();
+                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                    let len42 = vec42.len();
+                    ::core::mem::forget(vec42);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len42;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr42.cast_mut();
+                }
+            }
+        }
+    };
+    ptr36
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_secret_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_bytes_with_options_cabi<T: Guest>(
+    arg0: *mut u8,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len2 = l1;
+    let l3 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l4 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len5 = l4;
+    let l6 = i32::from(*arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l7 = i32::from(
+        *arg0.add(1 + 4 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l9 = i32::from(*arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l13 = i32::from(*arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l17 = *arg0.add(11 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l18 = *arg0.add(12 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base22 = l17;
+    let len22 = l18;
+    let mut result22 = _rt::Vec::with_capacity(len22);
+    for i in 0..len22 {
+        let base = base22.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e22 = {
+            let l19 = *base.add(0).cast::<*mut u8>();
+            let l20 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len21 = l20;
+            let bytes21 = _rt::Vec::from_raw_parts(l19.cast(), len21, len21);
+            _rt::string_lift(bytes21)
+        };
+        result22.push(e22);
+    }
+    _rt::cabi_dealloc(
+        base22,
+        len22 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l23 = i32::from(
+        *arg0.add(13 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l27 = i32::from(
+        *arg0.add(16 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l28 = i32::from(
+        *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result35 = T::encode_bytes_with_options(
+        _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+        _rt::Vec::from_raw_parts(l3.cast(), len5, len5),
+        EmbedOptions {
+            bits_per_channel: l6 as u8,
+            quality: match l7 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l8 = i32::from(
+                            *arg0
+                                .add(2 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l8 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l9 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l10 = *arg0
+                            .add(6 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l11 = *arg0
+                            .add(7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len12 = l11;
+                        let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+                        _rt::string_lift(bytes12)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l13 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l14 = *arg0
+                            .add(9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l15 = *arg0
+                            .add(10 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len16 = l15;
+                        let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+                        _rt::string_lift(bytes16)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result22,
+            signing_key: match l23 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l24 = *arg0
+                            .add(14 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l25 = *arg0
+                            .add(15 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len26 = l25;
+                        let bytes26 = _rt::Vec::from_raw_parts(l24.cast(), len26, len26);
+                        _rt::string_lift(bytes26)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l27 as u8,
+            decoy: match l28 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l29 = *arg0
+                            .add(18 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l30 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len31 = l30;
+                        let bytes31 = _rt::Vec::from_raw_parts(l29.cast(), len31, len31);
+                        let l32 = *arg0
+                            .add(20 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l33 = *arg0
+                            .add(21 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len34 = l33;
+                        let bytes34 = _rt::Vec::from_raw_parts(l32.cast(), len34, len34);
+                        Decoy {
+                            message: _rt::string_lift(bytes31),
+                            password: _rt::string_lift(bytes34),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        22 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr36 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result35 {
+        Ok(e) => {
+            *ptr36.add(0).cast::<u8>() = (0i32) as u8;
+            let vec37 = (e).into_boxed_slice();
+            let ptr37 = vec37.as_ptr().cast::<u8>();
+            let len37 = vec37.len();
+            ::core::mem::forget(vec37);
+            *ptr36.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len37;
+            *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr37
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr36.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                    let len38 = vec38.len();
+                    ::core::mem::forget(vec38);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len38;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr38.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                    let len39 = vec39.len();
+                    ::core::mem::forget(vec39);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len39;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr39.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                    let len40 = vec40.len();
+                    ::core::mem::forget(vec40);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len40;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr40.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec41 = (e.into_bytes()).into_boxed_slice();
+                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                    let len41 = vec41.len();
+                    ::core::mem::forget(vec41);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len41;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr41.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr36.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec42 = (e.into_bytes()).into_boxed_slice();
+                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                    let len42 = vec42.len();
+                    ::core::mem::forget(vec42);
+                    *ptr36
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len42;
+                    *ptr36
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr42.cast_mut();
+                }
+            }
+        }
+    };
+    ptr36
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_bytes_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_file_with_options_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len2 = l1;
+    let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+    let l3 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l4 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len5 = l4;
+    let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+    let l6 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l7 = *arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len8 = l7;
+    let l9 = *arg0.add(6 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l10 = *arg0.add(7 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len11 = l10;
+    let l12 = i32::from(*arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l13 = i32::from(
+        *arg0.add(1 + 8 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l15 = i32::from(*arg0.add(9 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l19 = i32::from(
+        *arg0.add(12 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l23 = *arg0.add(15 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l24 = *arg0.add(16 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base28 = l23;
+    let len28 = l24;
+    let mut result28 = _rt::Vec::with_capacity(len28);
+    for i in 0..len28 {
+        let base = base28.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e28 = {
+            let l25 = *base.add(0).cast::<*mut u8>();
+            let l26 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len27 = l26;
+            let bytes27 = _rt::Vec::from_raw_parts(l25.cast(), len27, len27);
+            _rt::string_lift(bytes27)
+        };
+        result28.push(e28);
+    }
+    _rt::cabi_dealloc(
+        base28,
+        len28 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l29 = i32::from(
+        *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l33 = i32::from(
+        *arg0.add(20 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l34 = i32::from(
+        *arg0.add(21 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result41 = T::encode_file_with_options(
+        FilePayload {
+            name: _rt::string_lift(bytes2),
+            media_type: _rt::string_lift(bytes5),
+            content: _rt::Vec::from_raw_parts(l6.cast(), len8, len8),
+        },
+        _rt::Vec::from_raw_parts(l9.cast(), len11, len11),
+        EmbedOptions {
+            bits_per_channel: l12 as u8,
+            quality: match l13 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l14 = i32::from(
+                            *arg0
+                                .add(2 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l14 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l15 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l16 = *arg0
+                            .add(10 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l17 = *arg0
+                            .add(11 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len18 = l17;
+                        let bytes18 = _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+                        _rt::string_lift(bytes18)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l19 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l20 = *arg0
+                            .add(13 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l21 = *arg0
+                            .add(14 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len22 = l21;
+                        let bytes22 = _rt::Vec::from_raw_parts(l20.cast(), len22, len22);
+                        _rt::string_lift(bytes22)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result28,
+            signing_key: match l29 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l30 = *arg0
+                            .add(18 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l31 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len32 = l31;
+                        let bytes32 = _rt::Vec::from_raw_parts(l30.cast(), len32, len32);
+                        _rt::string_lift(bytes32)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l33 as u8,
+            decoy: match l34 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l35 = *arg0
+                            .add(22 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l36 = *arg0
+                            .add(23 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len37 = l36;
+                        let bytes37 = _rt::Vec::from_raw_parts(l35.cast(), len37, len37);
+                        let l38 = *arg0
+                            .add(24 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l39 = *arg0
+                            .add(25 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len40 = l39;
+                        let bytes40 = _rt::Vec::from_raw_parts(l38.cast(), len40, len40);
+                        Decoy {
+                            message: _rt::string_lift(bytes37),
+                            password: _rt::string_lift(bytes40),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        26 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr42 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result41 {
+        Ok(e) => {
+            *ptr42.add(0).cast::<u8>() = (0i32) as u8;
+            let vec43 = (e).into_boxed_slice();
+            let ptr43 = vec43.as_ptr().cast::<u8>();
+            let len43 = vec43.len();
+            ::core::mem::forget(vec43);
+            *ptr42.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len43;
+            *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr43
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr42.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec44 = (e.into_bytes()).into_boxed_slice();
+                    let ptr44 = vec44.as_ptr().cast::<u8>();
+                    let len44 = vec44.len();
+                    ::core::mem::forget(vec44);
+                    *ptr42
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len44;
+                    *ptr42
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr44.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec45 = (e.into_bytes()).into_boxed_slice();
+                    let ptr45 = vec45.as_ptr().cast::<u8>();
+                    let len45 = vec45.len();
+                    ::core::mem::forget(vec45);
+                    *ptr42
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len45;
+                    *ptr42
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr45.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec46 = (e.into_bytes()).into_boxed_slice();
+                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                    let len46 = vec46.len();
+                    ::core::mem::forget(vec46);
+                    *ptr42
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len46;
+                    *ptr42
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr46.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec47 = (e.into_bytes()).into_boxed_slice();
+                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                    let len47 = vec47.len();
+                    ::core::mem::forget(vec47);
+                    *ptr42
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len47;
+                    *ptr42
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr47.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr42.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec48 = (e.into_bytes()).into_boxed_slice();
+                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                    let len48 = vec48.len();
+                    ::core::mem::forget(vec48);
+                    *ptr42
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len48;
+                    *ptr42
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr48.cast_mut();
+                }
+            }
+        }
+    };
+    ptr42
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_file_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_archive_with_options_cabi<T: Guest>(
+    arg0: *mut u8,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base11 = l0;
+    let len11 = l1;
+    let mut result11 = _rt::Vec::with_capacity(len11);
+    for i in 0..len11 {
+        let base = base11.add(i * (6 * ::core::mem::size_of::<*const u8>()));
+        let e11 = {
+            let l2 = *base.add(0).cast::<*mut u8>();
+            let l3 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len4 = l3;
+            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+            let l5 = *base
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l6 = *base.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len7 = l6;
+            let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+            let l8 = *base
+                .add(4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l9 = *base.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len10 = l9;
+            FilePayload {
+                name: _rt::string_lift(bytes4),
+                media_type: _rt::string_lift(bytes7),
+                content: _rt::Vec::from_raw_parts(l8.cast(), len10, len10),
+            }
+        };
+        result11.push(e11);
+    }
+    _rt::cabi_dealloc(
+        base11,
+        len11 * (6 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l12 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l13 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let len14 = l13;
+    let l15 = i32::from(*arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l16 = i32::from(
+        *arg0.add(1 + 4 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l18 = i32::from(*arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l22 = i32::from(*arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l26 = *arg0.add(11 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l27 = *arg0.add(12 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base31 = l26;
+    let len31 = l27;
+    let mut result31 = _rt::Vec::with_capacity(len31);
+    for i in 0..len31 {
+        let base = base31.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e31 = {
+            let l28 = *base.add(0).cast::<*mut u8>();
+            let l29 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len30 = l29;
+            let bytes30 = _rt::Vec::from_raw_parts(l28.cast(), len30, len30);
+            _rt::string_lift(bytes30)
+        };
+        result31.push(e31);
+    }
+    _rt::cabi_dealloc(
+        base31,
+        len31 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l32 = i32::from(
+        *arg0.add(13 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l36 = i32::from(
+        *arg0.add(16 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l37 = i32::from(
+        *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result44 = T::encode_archive_with_options(
+        result11,
+        _rt::Vec::from_raw_parts(l12.cast(), len14, len14),
+        EmbedOptions {
+            bits_per_channel: l15 as u8,
+            quality: match l16 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l17 = i32::from(
+                            *arg0
+                                .add(2 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l17 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l18 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l19 = *arg0
+                            .add(6 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l20 = *arg0
+                            .add(7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len21 = l20;
+                        let bytes21 = _rt::Vec::from_raw_parts(l19.cast(), len21, len21);
+                        _rt::string_lift(bytes21)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l22 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l23 = *arg0
+                            .add(9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l24 = *arg0
+                            .add(10 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len25 = l24;
+                        let bytes25 = _rt::Vec::from_raw_parts(l23.cast(), len25, len25);
+                        _rt::string_lift(bytes25)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result31,
+            signing_key: match l32 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l33 = *arg0
+                            .add(14 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l34 = *arg0
+                            .add(15 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len35 = l34;
+                        let bytes35 = _rt::Vec::from_raw_parts(l33.cast(), len35, len35);
+                        _rt::string_lift(bytes35)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l36 as u8,
+            decoy: match l37 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l38 = *arg0
+                            .add(18 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l39 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len40 = l39;
+                        let bytes40 = _rt::Vec::from_raw_parts(l38.cast(), len40, len40);
+                        let l41 = *arg0
+                            .add(20 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l42 = *arg0
+                            .add(21 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len43 = l42;
+                        let bytes43 = _rt::Vec::from_raw_parts(l41.cast(), len43, len43);
+                        Decoy {
+                            message: _rt::string_lift(bytes40),
+                            password: _rt::string_lift(bytes43),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        22 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr45 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result44 {
+        Ok(e) => {
+            *ptr45.add(0).cast::<u8>() = (0i32) as u8;
+            let vec46 = (e).into_boxed_slice();
+            let ptr46 = vec46.as_ptr().cast::<u8>();
+            let len46 = vec46.len();
+            ::core::mem::forget(vec46);
+            *ptr45.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len46;
+            *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr46
+                .cast_mut();
+        }
+        Err(e) => {
+            *ptr45.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec47 = (e.into_bytes()).into_boxed_slice();
+                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                    let len47 = vec47.len();
+                    ::core::mem::forget(vec47);
+                    *ptr45
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len47;
+                    *ptr45
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr47.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec48 = (e.into_bytes()).into_boxed_slice();
+                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                    let len48 = vec48.len();
+                    ::core::mem::forget(vec48);
+                    *ptr45
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len48;
+                    *ptr45
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr48.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec49 = (e.into_bytes()).into_boxed_slice();
+                    let ptr49 = vec49.as_ptr().cast::<u8>();
+                    let len49 = vec49.len();
+                    ::core::mem::forget(vec49);
+                    *ptr45
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len49;
+                    *ptr45
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr49.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec50 = (e.into_bytes()).into_boxed_slice();
+                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                    let len50 = vec50.len();
+                    ::core::mem::forget(vec50);
+                    *ptr45
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len50;
+                    *ptr45
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr50.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr45.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec51 = (e.into_bytes()).into_boxed_slice();
+                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                    let len51 = vec51.len();
+                    ::core::mem::forget(vec51);
+                    *ptr45
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len51;
+                    *ptr45
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr51.cast_mut();
+                }
+            }
+        }
+    };
+    ptr45
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_archive_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {
+                    let l5 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                1 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                5 => {}
+                6 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
         }
-        unsafe { wit_import1(ptr0.cast_mut(), len0) };
     }
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_encode_secret_into_bmp_cabi<T: Guest>(
+pub unsafe fn _export_encode_split_with_options_cabi<T: Guest>(
     arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let result2 = T::encode_secret_into_bmp(
-        _rt::string_lift(bytes0),
-        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    let v28 = match l0 {
+        0 => {
+            let e28 = {
+                let l1 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l2 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len3 = l2;
+                let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                _rt::string_lift(bytes3)
+            };
+            HiddenContent::Text(e28)
+        }
+        1 => {
+            let e28 = {
+                let l4 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l5 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len6 = l5;
+                _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
+            };
+            HiddenContent::Binary(e28)
+        }
+        2 => {
+            let e28 = {
+                let l7 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l8 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len9 = l8;
+                let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                let l10 = *arg0
+                    .add(3 * ::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l11 = *arg0
+                    .add(4 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len12 = l11;
+                let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+                let l13 = *arg0
+                    .add(5 * ::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l14 = *arg0
+                    .add(6 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len15 = l14;
+                FilePayload {
+                    name: _rt::string_lift(bytes9),
+                    media_type: _rt::string_lift(bytes12),
+                    content: _rt::Vec::from_raw_parts(l13.cast(), len15, len15),
+                }
+            };
+            HiddenContent::File(e28)
+        }
+        n => {
+            debug_assert_eq!(n, 3, "invalid enum discriminant");
+            let e28 = {
+                let l16 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l17 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let base27 = l16;
+                let len27 = l17;
+                let mut result27 = _rt::Vec::with_capacity(len27);
+                for i in 0..len27 {
+                    let base = base27.add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                    let e27 = {
+                        let l18 = *base.add(0).cast::<*mut u8>();
+                        let l19 = *base
+                            .add(::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len20 = l19;
+                        let bytes20 = _rt::Vec::from_raw_parts(l18.cast(), len20, len20);
+                        let l21 = *base
+                            .add(2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l22 = *base
+                            .add(3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len23 = l22;
+                        let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
+                        let l24 = *base
+                            .add(4 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l25 = *base
+                            .add(5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len26 = l25;
+                        FilePayload {
+                            name: _rt::string_lift(bytes20),
+                            media_type: _rt::string_lift(bytes23),
+                            content: _rt::Vec::from_raw_parts(l24.cast(), len26, len26),
+                        }
+                    };
+                    result27.push(e27);
+                }
+                _rt::cabi_dealloc(
+                    base27,
+                    len27 * (6 * ::core::mem::size_of::<*const u8>()),
+                    ::core::mem::size_of::<*const u8>(),
+                );
+                result27
+            };
+            HiddenContent::Archive(e28)
+        }
+    };
+    let l29 = *arg0.add(7 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l30 = *arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base34 = l29;
+    let len34 = l30;
+    let mut result34 = _rt::Vec::with_capacity(len34);
+    for i in 0..len34 {
+        let base = base34.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e34 = {
+            let l31 = *base.add(0).cast::<*mut u8>();
+            let l32 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len33 = l32;
+            _rt::Vec::from_raw_parts(l31.cast(), len33, len33)
+        };
+        result34.push(e34);
+    }
+    _rt::cabi_dealloc(
+        base34,
+        len34 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
     );
-    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec4 = (result2).into_boxed_slice();
-    let ptr4 = vec4.as_ptr().cast::<u8>();
-    let len4 = vec4.len();
-    ::core::mem::forget(vec4);
-    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
-    *ptr3.add(0).cast::<*mut u8>() = ptr4.cast_mut();
-    ptr3
+    let l35 = i32::from(*arg0.add(9 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l36 = i32::from(
+        *arg0.add(1 + 9 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l38 = i32::from(
+        *arg0.add(10 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l42 = i32::from(
+        *arg0.add(13 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l46 = *arg0.add(16 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l47 = *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base51 = l46;
+    let len51 = l47;
+    let mut result51 = _rt::Vec::with_capacity(len51);
+    for i in 0..len51 {
+        let base = base51.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e51 = {
+            let l48 = *base.add(0).cast::<*mut u8>();
+            let l49 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len50 = l49;
+            let bytes50 = _rt::Vec::from_raw_parts(l48.cast(), len50, len50);
+            _rt::string_lift(bytes50)
+        };
+        result51.push(e51);
+    }
+    _rt::cabi_dealloc(
+        base51,
+        len51 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l52 = i32::from(
+        *arg0.add(18 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l56 = i32::from(
+        *arg0.add(21 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l57 = i32::from(
+        *arg0.add(22 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result64 = T::encode_split_with_options(
+        v28,
+        result34,
+        EmbedOptions {
+            bits_per_channel: l35 as u8,
+            quality: match l36 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l37 = i32::from(
+                            *arg0
+                                .add(2 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l37 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l38 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l39 = *arg0
+                            .add(11 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l40 = *arg0
+                            .add(12 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len41 = l40;
+                        let bytes41 = _rt::Vec::from_raw_parts(l39.cast(), len41, len41);
+                        _rt::string_lift(bytes41)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l42 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l43 = *arg0
+                            .add(14 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l44 = *arg0
+                            .add(15 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len45 = l44;
+                        let bytes45 = _rt::Vec::from_raw_parts(l43.cast(), len45, len45);
+                        _rt::string_lift(bytes45)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result51,
+            signing_key: match l52 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l53 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l54 = *arg0
+                            .add(20 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len55 = l54;
+                        let bytes55 = _rt::Vec::from_raw_parts(l53.cast(), len55, len55);
+                        _rt::string_lift(bytes55)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l56 as u8,
+            decoy: match l57 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l58 = *arg0
+                            .add(23 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l59 = *arg0
+                            .add(24 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len60 = l59;
+                        let bytes60 = _rt::Vec::from_raw_parts(l58.cast(), len60, len60);
+                        let l61 = *arg0
+                            .add(25 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l62 = *arg0
+                            .add(26 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len63 = l62;
+                        let bytes63 = _rt::Vec::from_raw_parts(l61.cast(), len63, len63);
+                        Decoy {
+                            message: _rt::string_lift(bytes60),
+                            password: _rt::string_lift(bytes63),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        27 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr65 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result64 {
+        Ok(e) => {
+            *ptr65.add(0).cast::<u8>() = (0i32) as u8;
+            let vec67 = e;
+            let len67 = vec67.len();
+            let layout67 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec67.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result67 = if layout67.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout67).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout67);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec67.into_iter().enumerate() {
+                let base = result67.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let vec66 = (e).into_boxed_slice();
+                    let ptr66 = vec66.as_ptr().cast::<u8>();
+                    let len66 = vec66.len();
+                    ::core::mem::forget(vec66);
+                    *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len66;
+                    *base.add(0).cast::<*mut u8>() = ptr66.cast_mut();
+                }
+            }
+            *ptr65.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len67;
+            *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result67;
+        }
+        Err(e) => {
+            *ptr65.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec68 = (e.into_bytes()).into_boxed_slice();
+                    let ptr68 = vec68.as_ptr().cast::<u8>();
+                    let len68 = vec68.len();
+                    ::core::mem::forget(vec68);
+                    *ptr65
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len68;
+                    *ptr65
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr68.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec69 = (e.into_bytes()).into_boxed_slice();
+                    let ptr69 = vec69.as_ptr().cast::<u8>();
+                    let len69 = vec69.len();
+                    ::core::mem::forget(vec69);
+                    *ptr65
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len69;
+                    *ptr65
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr69.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec70 = (e.into_bytes()).into_boxed_slice();
+                    let ptr70 = vec70.as_ptr().cast::<u8>();
+                    let len70 = vec70.len();
+                    ::core::mem::forget(vec70);
+                    *ptr65
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len70;
+                    *ptr65
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr70.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec71 = (e.into_bytes()).into_boxed_slice();
+                    let ptr71 = vec71.as_ptr().cast::<u8>();
+                    let len71 = vec71.len();
+                    ::core::mem::forget(vec71);
+                    *ptr65
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len71;
+                    *ptr65
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr71.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr65.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec72 = (e.into_bytes()).into_boxed_slice();
+                    let ptr72 = vec72.as_ptr().cast::<u8>();
+                    let len72 = vec72.len();
+                    ::core::mem::forget(vec72);
+                    *ptr65
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len72;
+                    *ptr65
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr72.cast_mut();
+                }
+            }
+        }
+    };
+    ptr65
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_encode_secret_into_bmp<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_encode_split_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base6 = l1;
+            let len6 = l2;
+            for i in 0..len6 {
+                let base = base6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base5 = l3;
+                    let len5 = l4;
+                    _rt::cabi_dealloc(base5, len5 * 1, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base6,
+                len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+        }
+        _ => {
+            let l7 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l7 {
+                0 => {
+                    let l8 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                1 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+                5 => {}
+                6 => {
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l14, l15, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l16 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l17 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l16, l17, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_encode_shared_with_options_cabi<T: Guest>(
+    arg0: *mut u8,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    let v28 = match l0 {
+        0 => {
+            let e28 = {
+                let l1 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l2 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len3 = l2;
+                let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                _rt::string_lift(bytes3)
+            };
+            HiddenContent::Text(e28)
+        }
+        1 => {
+            let e28 = {
+                let l4 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l5 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len6 = l5;
+                _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
+            };
+            HiddenContent::Binary(e28)
+        }
+        2 => {
+            let e28 = {
+                let l7 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l8 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len9 = l8;
+                let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                let l10 = *arg0
+                    .add(3 * ::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l11 = *arg0
+                    .add(4 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len12 = l11;
+                let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+                let l13 = *arg0
+                    .add(5 * ::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l14 = *arg0
+                    .add(6 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let len15 = l14;
+                FilePayload {
+                    name: _rt::string_lift(bytes9),
+                    media_type: _rt::string_lift(bytes12),
+                    content: _rt::Vec::from_raw_parts(l13.cast(), len15, len15),
+                }
+            };
+            HiddenContent::File(e28)
+        }
+        n => {
+            debug_assert_eq!(n, 3, "invalid enum discriminant");
+            let e28 = {
+                let l16 = *arg0
+                    .add(::core::mem::size_of::<*const u8>())
+                    .cast::<*mut u8>();
+                let l17 = *arg0
+                    .add(2 * ::core::mem::size_of::<*const u8>())
+                    .cast::<usize>();
+                let base27 = l16;
+                let len27 = l17;
+                let mut result27 = _rt::Vec::with_capacity(len27);
+                for i in 0..len27 {
+                    let base = base27.add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                    let e27 = {
+                        let l18 = *base.add(0).cast::<*mut u8>();
+                        let l19 = *base
+                            .add(::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len20 = l19;
+                        let bytes20 = _rt::Vec::from_raw_parts(l18.cast(), len20, len20);
+                        let l21 = *base
+                            .add(2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l22 = *base
+                            .add(3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len23 = l22;
+                        let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
+                        let l24 = *base
+                            .add(4 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l25 = *base
+                            .add(5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len26 = l25;
+                        FilePayload {
+                            name: _rt::string_lift(bytes20),
+                            media_type: _rt::string_lift(bytes23),
+                            content: _rt::Vec::from_raw_parts(l24.cast(), len26, len26),
+                        }
+                    };
+                    result27.push(e27);
+                }
+                _rt::cabi_dealloc(
+                    base27,
+                    len27 * (6 * ::core::mem::size_of::<*const u8>()),
+                    ::core::mem::size_of::<*const u8>(),
+                );
+                result27
+            };
+            HiddenContent::Archive(e28)
+        }
+    };
+    let l29 = *arg0.add(7 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l30 = *arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base34 = l29;
+    let len34 = l30;
+    let mut result34 = _rt::Vec::with_capacity(len34);
+    for i in 0..len34 {
+        let base = base34.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e34 = {
+            let l31 = *base.add(0).cast::<*mut u8>();
+            let l32 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len33 = l32;
+            _rt::Vec::from_raw_parts(l31.cast(), len33, len33)
+        };
+        result34.push(e34);
+    }
+    _rt::cabi_dealloc(
+        base34,
+        len34 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l35 = i32::from(*arg0.add(9 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l36 = i32::from(
+        *arg0.add(10 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l37 = i32::from(
+        *arg0.add(1 + 10 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l39 = i32::from(
+        *arg0.add(11 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l43 = i32::from(
+        *arg0.add(14 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l47 = *arg0.add(17 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l48 = *arg0.add(18 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base52 = l47;
+    let len52 = l48;
+    let mut result52 = _rt::Vec::with_capacity(len52);
+    for i in 0..len52 {
+        let base = base52.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e52 = {
+            let l49 = *base.add(0).cast::<*mut u8>();
+            let l50 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len51 = l50;
+            let bytes51 = _rt::Vec::from_raw_parts(l49.cast(), len51, len51);
+            _rt::string_lift(bytes51)
+        };
+        result52.push(e52);
+    }
+    _rt::cabi_dealloc(
+        base52,
+        len52 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l53 = i32::from(
+        *arg0.add(19 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l57 = i32::from(
+        *arg0.add(22 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l58 = i32::from(
+        *arg0.add(23 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result65 = T::encode_shared_with_options(
+        v28,
+        result34,
+        l35 as u8,
+        EmbedOptions {
+            bits_per_channel: l36 as u8,
+            quality: match l37 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l38 = i32::from(
+                            *arg0
+                                .add(2 + 10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l38 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l39 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l40 = *arg0
+                            .add(12 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l41 = *arg0
+                            .add(13 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len42 = l41;
+                        let bytes42 = _rt::Vec::from_raw_parts(l40.cast(), len42, len42);
+                        _rt::string_lift(bytes42)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l43 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l44 = *arg0
+                            .add(15 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l45 = *arg0
+                            .add(16 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len46 = l45;
+                        let bytes46 = _rt::Vec::from_raw_parts(l44.cast(), len46, len46);
+                        _rt::string_lift(bytes46)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result52,
+            signing_key: match l53 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l54 = *arg0
+                            .add(20 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l55 = *arg0
+                            .add(21 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len56 = l55;
+                        let bytes56 = _rt::Vec::from_raw_parts(l54.cast(), len56, len56);
+                        _rt::string_lift(bytes56)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l57 as u8,
+            decoy: match l58 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l59 = *arg0
+                            .add(24 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l60 = *arg0
+                            .add(25 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len61 = l60;
+                        let bytes61 = _rt::Vec::from_raw_parts(l59.cast(), len61, len61);
+                        let l62 = *arg0
+                            .add(26 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l63 = *arg0
+                            .add(27 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len64 = l63;
+                        let bytes64 = _rt::Vec::from_raw_parts(l62.cast(), len64, len64);
+                        Decoy {
+                            message: _rt::string_lift(bytes61),
+                            password: _rt::string_lift(bytes64),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        28 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr66 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result65 {
+        Ok(e) => {
+            *ptr66.add(0).cast::<u8>() = (0i32) as u8;
+            let vec68 = e;
+            let len68 = vec68.len();
+            let layout68 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec68.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result68 = if layout68.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout68).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout68);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec68.into_iter().enumerate() {
+                let base = result68.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let vec67 = (e).into_boxed_slice();
+                    let ptr67 = vec67.as_ptr().cast::<u8>();
+                    let len67 = vec67.len();
+                    ::core::mem::forget(vec67);
+                    *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len67;
+                    *base.add(0).cast::<*mut u8>() = ptr67.cast_mut();
+                }
+            }
+            *ptr66.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len68;
+            *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = result68;
+        }
+        Err(e) => {
+            *ptr66.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec69 = (e.into_bytes()).into_boxed_slice();
+                    let ptr69 = vec69.as_ptr().cast::<u8>();
+                    let len69 = vec69.len();
+                    ::core::mem::forget(vec69);
+                    *ptr66
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len69;
+                    *ptr66
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr69.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec70 = (e.into_bytes()).into_boxed_slice();
+                    let ptr70 = vec70.as_ptr().cast::<u8>();
+                    let len70 = vec70.len();
+                    ::core::mem::forget(vec70);
+                    *ptr66
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len70;
+                    *ptr66
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr70.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec71 = (e.into_bytes()).into_boxed_slice();
+                    let ptr71 = vec71.as_ptr().cast::<u8>();
+                    let len71 = vec71.len();
+                    ::core::mem::forget(vec71);
+                    *ptr66
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len71;
+                    *ptr66
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr71.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec72 = (e.into_bytes()).into_boxed_slice();
+                    let ptr72 = vec72.as_ptr().cast::<u8>();
+                    let len72 = vec72.len();
+                    ::core::mem::forget(vec72);
+                    *ptr66
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len72;
+                    *ptr66
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr72.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr66.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec73 = (e.into_bytes()).into_boxed_slice();
+                    let ptr73 = vec73.as_ptr().cast::<u8>();
+                    let len73 = vec73.len();
+                    ::core::mem::forget(vec73);
+                    *ptr66
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len73;
+                    *ptr66
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr73.cast_mut();
+                }
+            }
+        }
+    };
+    ptr66
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_encode_shared_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base6 = l1;
+            let len6 = l2;
+            for i in 0..len6 {
+                let base = base6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base5 = l3;
+                    let len5 = l4;
+                    _rt::cabi_dealloc(base5, len5 * 1, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base6,
+                len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+        }
+        _ => {
+            let l7 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l7 {
+                0 => {
+                    let l8 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                1 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+                5 => {}
+                6 => {
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l14, l15, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l16 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l17 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l16, l17, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_capacity_with_options_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
     let l0 = *arg0.add(0).cast::<*mut u8>();
     let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    let base2 = l0;
     let len2 = l1;
-    _rt::cabi_dealloc(base2, len2 * 1, 1);
+    let l3 = i32::from(*arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l4 = i32::from(
+        *arg0.add(1 + 2 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l6 = i32::from(*arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l10 = i32::from(*arg0.add(6 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
+    let l14 = *arg0.add(9 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l15 = *arg0.add(10 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    let base19 = l14;
+    let len19 = l15;
+    let mut result19 = _rt::Vec::with_capacity(len19);
+    for i in 0..len19 {
+        let base = base19.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e19 = {
+            let l16 = *base.add(0).cast::<*mut u8>();
+            let l17 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len18 = l17;
+            let bytes18 = _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+            _rt::string_lift(bytes18)
+        };
+        result19.push(e19);
+    }
+    _rt::cabi_dealloc(
+        base19,
+        len19 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let l20 = i32::from(
+        *arg0.add(11 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l24 = i32::from(
+        *arg0.add(14 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let l25 = i32::from(
+        *arg0.add(15 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+    );
+    let result32 = T::capacity_with_options(
+        _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+        EmbedOptions {
+            bits_per_channel: l3 as u8,
+            quality: match l4 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l5 = i32::from(
+                            *arg0
+                                .add(2 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>(),
+                        );
+                        l5 as u8
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            key: match l6 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l7 = *arg0
+                            .add(4 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l8 = *arg0
+                            .add(5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len9 = l8;
+                        let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                        _rt::string_lift(bytes9)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match l10 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l11 = *arg0
+                            .add(7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l12 = *arg0
+                            .add(8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len13 = l12;
+                        let bytes13 = _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+                        _rt::string_lift(bytes13)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            recipients: result19,
+            signing_key: match l20 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l21 = *arg0
+                            .add(12 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l22 = *arg0
+                            .add(13 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len23 = l22;
+                        let bytes23 = _rt::Vec::from_raw_parts(l21.cast(), len23, len23);
+                        _rt::string_lift(bytes23)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            error_correction: l24 as u8,
+            decoy: match l25 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let l26 = *arg0
+                            .add(16 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l27 = *arg0
+                            .add(17 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len28 = l27;
+                        let bytes28 = _rt::Vec::from_raw_parts(l26.cast(), len28, len28);
+                        let l29 = *arg0
+                            .add(18 * ::core::mem::size_of::<*const u8>())
+                            .cast::<*mut u8>();
+                        let l30 = *arg0
+                            .add(19 * ::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len31 = l30;
+                        let bytes31 = _rt::Vec::from_raw_parts(l29.cast(), len31, len31);
+                        Decoy {
+                            message: _rt::string_lift(bytes28),
+                            password: _rt::string_lift(bytes31),
+                        }
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+        },
+    );
+    _rt::cabi_dealloc(
+        arg0,
+        20 * ::core::mem::size_of::<*const u8>(),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let ptr33 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result32 {
+        Ok(e) => {
+            *ptr33.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr33.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr33.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr33.add(8).cast::<u8>() = (0i32) as u8;
+                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                    let len34 = vec34.len();
+                    ::core::mem::forget(vec34);
+                    *ptr33
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len34;
+                    *ptr33
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr34.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr33.add(8).cast::<u8>() = (1i32) as u8;
+                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                    let len35 = vec35.len();
+                    ::core::mem::forget(vec35);
+                    *ptr33
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len35;
+                    *ptr33
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr35.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr33.add(8).cast::<u8>() = (2i32) as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr33.add(8).cast::<u8>() = (3i32) as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr33.add(8).cast::<u8>() = (4i32) as u8;
+                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                    let len36 = vec36.len();
+                    ::core::mem::forget(vec36);
+                    *ptr33
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len36;
+                    *ptr33
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr36.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr33.add(8).cast::<u8>() = (5i32) as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr33.add(8).cast::<u8>() = (6i32) as u8;
+                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                    let len37 = vec37.len();
+                    ::core::mem::forget(vec37);
+                    *ptr33
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len37;
+                    *ptr33
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr37.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr33.add(8).cast::<u8>() = (7i32) as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr33.add(8).cast::<u8>() = (8i32) as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr33.add(8).cast::<u8>() = (9i32) as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr33.add(8).cast::<u8>() = (10i32) as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr33.add(8).cast::<u8>() = (11i32) as u8;
+                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                    let len38 = vec38.len();
+                    ::core::mem::forget(vec38);
+                    *ptr33
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len38;
+                    *ptr33
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr38.cast_mut();
+                }
+            }
+        }
+    };
+    ptr33
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_decode_secret_from_bmp_cabi<T: Guest>(
+pub unsafe fn __post_return_capacity_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l6 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                5 => {}
+                6 => {
+                    let l8 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_secret_with_options_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: *mut u8,
+    arg9: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
     let len0 = arg1;
-    let result1 = T::decode_secret_from_bmp(
+    let base6 = arg8;
+    let len6 = arg9;
+    let mut result6 = _rt::Vec::with_capacity(len6);
+    for i in 0..len6 {
+        let base = base6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e6 = {
+            let l3 = *base.add(0).cast::<*mut u8>();
+            let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            _rt::string_lift(bytes5)
+        };
+        result6.push(e6);
+    }
+    _rt::cabi_dealloc(
+        base6,
+        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result7 = T::decode_secret_with_options(
         _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        ExtractOptions {
+            key: match arg2 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len1 = arg4;
+                        let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                        _rt::string_lift(bytes1)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match arg5 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len2 = arg7;
+                        let bytes2 = _rt::Vec::from_raw_parts(arg6.cast(), len2, len2);
+                        _rt::string_lift(bytes2)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            identities: result6,
+        },
     );
-    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec3 = (result1.into_bytes()).into_boxed_slice();
-    let ptr3 = vec3.as_ptr().cast::<u8>();
-    let len3 = vec3.len();
-    ::core::mem::forget(vec3);
-    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
-    ptr2
+    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result7 {
+        Ok(e) => {
+            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+            let DecodedSecret {
+                secret: secret9,
+                signature: signature9,
+                corrected_errors: corrected_errors9,
+            } = e;
+            let vec10 = (secret9.into_bytes()).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr8.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+            *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr10
+                .cast_mut();
+            match signature9 {
+                SignatureStatus::Unsigned => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                }
+                SignatureStatus::Valid(e) => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *ptr8.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
+                    *ptr8
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr11.cast_mut();
+                }
+                SignatureStatus::Invalid(e) => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                    let len12 = vec12.len();
+                    ::core::mem::forget(vec12);
+                    *ptr8.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len12;
+                    *ptr8
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr12.cast_mut();
+                }
+            }
+            *ptr8.add(6 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
+                corrected_errors9,
+            );
+        }
+        Err(e) => {
+            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len13;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                    let len15 = vec15.len();
+                    ::core::mem::forget(vec15);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr15.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                    let len16 = vec16.len();
+                    ::core::mem::forget(vec16);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr16.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                    let len17 = vec17.len();
+                    ::core::mem::forget(vec17);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len17;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr17.cast_mut();
+                }
+            }
+        }
+    };
+    ptr8
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_decode_secret_from_bmp<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
+pub unsafe fn __post_return_decode_secret_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+            let l3 = i32::from(
+                *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l3 {
+                0 => {}
+                1 => {
+                    let l4 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                _ => {
+                    let l6 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+            }
+        }
+        _ => {
+            let l8 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l8 {
+                0 => {
+                    let l9 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+                1 => {
+                    let l11 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l13 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+                5 => {}
+                6 => {
+                    let l15 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l16 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l15, l16, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l17 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l17, l18, 1);
+                }
+            }
+        }
+    }
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_encode_secret_into_jpeg_cabi<T: Guest>(
+pub unsafe fn _export_decode_bytes_with_options_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: *mut u8,
+    arg9: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
     let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let result2 = T::encode_secret_into_jpeg(
-        _rt::string_lift(bytes0),
-        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    let base6 = arg8;
+    let len6 = arg9;
+    let mut result6 = _rt::Vec::with_capacity(len6);
+    for i in 0..len6 {
+        let base = base6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e6 = {
+            let l3 = *base.add(0).cast::<*mut u8>();
+            let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            _rt::string_lift(bytes5)
+        };
+        result6.push(e6);
+    }
+    _rt::cabi_dealloc(
+        base6,
+        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
     );
-    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec4 = (result2).into_boxed_slice();
-    let ptr4 = vec4.as_ptr().cast::<u8>();
-    let len4 = vec4.len();
-    ::core::mem::forget(vec4);
-    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
-    *ptr3.add(0).cast::<*mut u8>() = ptr4.cast_mut();
-    ptr3
+    let result7 = T::decode_bytes_with_options(
+        _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        ExtractOptions {
+            key: match arg2 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len1 = arg4;
+                        let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                        _rt::string_lift(bytes1)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match arg5 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len2 = arg7;
+                        let bytes2 = _rt::Vec::from_raw_parts(arg6.cast(), len2, len2);
+                        _rt::string_lift(bytes2)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            identities: result6,
+        },
+    );
+    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result7 {
+        Ok(e) => {
+            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+            let DecodedBytes {
+                payload: payload9,
+                signature: signature9,
+                corrected_errors: corrected_errors9,
+            } = e;
+            let vec10 = (payload9).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr8.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+            *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr10
+                .cast_mut();
+            match signature9 {
+                SignatureStatus::Unsigned => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                }
+                SignatureStatus::Valid(e) => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *ptr8.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
+                    *ptr8
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr11.cast_mut();
+                }
+                SignatureStatus::Invalid(e) => {
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                    let len12 = vec12.len();
+                    ::core::mem::forget(vec12);
+                    *ptr8.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len12;
+                    *ptr8
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr12.cast_mut();
+                }
+            }
+            *ptr8.add(6 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
+                corrected_errors9,
+            );
+        }
+        Err(e) => {
+            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len13;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                    let len15 = vec15.len();
+                    ::core::mem::forget(vec15);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr15.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                    let len16 = vec16.len();
+                    ::core::mem::forget(vec16);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len16;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr16.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                    let len17 = vec17.len();
+                    ::core::mem::forget(vec17);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len17;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr17.cast_mut();
+                }
+            }
+        }
+    };
+    ptr8
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_encode_secret_into_jpeg<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    let base2 = l0;
-    let len2 = l1;
-    _rt::cabi_dealloc(base2, len2 * 1, 1);
+pub unsafe fn __post_return_decode_bytes_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+            let l4 = i32::from(
+                *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l4 {
+                0 => {}
+                1 => {
+                    let l5 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+                _ => {
+                    let l7 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+            }
+        }
+        _ => {
+            let l9 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l9 {
+                0 => {
+                    let l10 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+                1 => {
+                    let l12 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l14, l15, 1);
+                }
+                5 => {}
+                6 => {
+                    let l16 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l17 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l16, l17, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l18 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l19 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l18, l19, 1);
+                }
+            }
+        }
+    }
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_decode_secret_from_jpeg_cabi<T: Guest>(
+pub unsafe fn _export_decode_with_options_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: *mut u8,
+    arg9: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
     let len0 = arg1;
-    let result1 = T::decode_secret_from_jpeg(
+    let base6 = arg8;
+    let len6 = arg9;
+    let mut result6 = _rt::Vec::with_capacity(len6);
+    for i in 0..len6 {
+        let base = base6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e6 = {
+            let l3 = *base.add(0).cast::<*mut u8>();
+            let l4 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len5 = l4;
+            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+            _rt::string_lift(bytes5)
+        };
+        result6.push(e6);
+    }
+    _rt::cabi_dealloc(
+        base6,
+        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result7 = T::decode_with_options(
         _rt::Vec::from_raw_parts(arg0.cast(), len0, len0),
+        ExtractOptions {
+            key: match arg2 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len1 = arg4;
+                        let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                        _rt::string_lift(bytes1)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match arg5 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len2 = arg7;
+                        let bytes2 = _rt::Vec::from_raw_parts(arg6.cast(), len2, len2);
+                        _rt::string_lift(bytes2)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            identities: result6,
+        },
     );
-    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec3 = (result1.into_bytes()).into_boxed_slice();
+    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result7 {
+        Ok(e) => {
+            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+            let DecodedPayload {
+                content: content9,
+                signature: signature9,
+                corrected_errors: corrected_errors9,
+            } = e;
+            match content9 {
+                HiddenContent::Text(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                    let len10 = vec10.len();
+                    ::core::mem::forget(vec10);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr10.cast_mut();
+                }
+                HiddenContent::Binary(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec11 = (e).into_boxed_slice();
+                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                    let len11 = vec11.len();
+                    ::core::mem::forget(vec11);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len11;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr11.cast_mut();
+                }
+                HiddenContent::File(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let FilePayload {
+                        name: name12,
+                        media_type: media_type12,
+                        content: content12,
+                    } = e;
+                    let vec13 = (name12.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len13;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                    let vec14 = (media_type12.into_bytes()).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *ptr8.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len14;
+                    *ptr8
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                    let vec15 = (content12).into_boxed_slice();
+                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                    let len15 = vec15.len();
+                    ::core::mem::forget(vec15);
+                    *ptr8.add(7 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                    *ptr8
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr15.cast_mut();
+                }
+                HiddenContent::Archive(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                    let vec20 = e;
+                    let len20 = vec20.len();
+                    let layout20 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec20.len() * (6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result20 = if layout20.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout20).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout20);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec20.into_iter().enumerate() {
+                        let base = result20
+                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let FilePayload {
+                                name: name16,
+                                media_type: media_type16,
+                                content: content16,
+                            } = e;
+                            let vec17 = (name16.into_bytes()).into_boxed_slice();
+                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                            let len17 = vec17.len();
+                            ::core::mem::forget(vec17);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len17;
+                            *base.add(0).cast::<*mut u8>() = ptr17.cast_mut();
+                            let vec18 = (media_type16.into_bytes()).into_boxed_slice();
+                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                            let len18 = vec18.len();
+                            ::core::mem::forget(vec18);
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len18;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr18.cast_mut();
+                            let vec19 = (content16).into_boxed_slice();
+                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                            let len19 = vec19.len();
+                            ::core::mem::forget(vec19);
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len19;
+                            *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr19.cast_mut();
+                        }
+                    }
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len20;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result20;
+                }
+            }
+            match signature9 {
+                SignatureStatus::Unsigned => {
+                    *ptr8.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                }
+                SignatureStatus::Valid(e) => {
+                    *ptr8.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                    let len21 = vec21.len();
+                    ::core::mem::forget(vec21);
+                    *ptr8
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len21;
+                    *ptr8
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr21.cast_mut();
+                }
+                SignatureStatus::Invalid(e) => {
+                    *ptr8.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                    let len22 = vec22.len();
+                    ::core::mem::forget(vec22);
+                    *ptr8
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len22;
+                    *ptr8
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr22.cast_mut();
+                }
+            }
+            *ptr8.add(11 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
+                corrected_errors9,
+            );
+        }
+        Err(e) => {
+            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                    let len23 = vec23.len();
+                    ::core::mem::forget(vec23);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len23;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr23.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                    let len24 = vec24.len();
+                    ::core::mem::forget(vec24);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len24;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr24.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                    let len25 = vec25.len();
+                    ::core::mem::forget(vec25);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len25;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr25.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                    let len26 = vec26.len();
+                    ::core::mem::forget(vec26);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len26;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr26.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr8.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len27;
+                    *ptr8
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                }
+            }
+        }
+    };
+    ptr8
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base6 = l4;
+                    let len6 = l5;
+                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                }
+                2 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                    let l9 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                    let l11 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base13 = l11;
+                    let len13 = l12;
+                    _rt::cabi_dealloc(base13, len13 * 1, 1);
+                }
+                _ => {
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base23 = l14;
+                    let len23 = l15;
+                    for i in 0..len23 {
+                        let base = base23
+                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l16 = *base.add(0).cast::<*mut u8>();
+                            let l17 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l16, l17, 1);
+                            let l18 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l19 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l18, l19, 1);
+                            let l20 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l21 = *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l20;
+                            let len22 = l21;
+                            _rt::cabi_dealloc(base22, len22 * 1, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base23,
+                        len23 * (6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+            }
+            let l24 = i32::from(
+                *arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l24 {
+                0 => {}
+                1 => {
+                    let l25 = *arg0
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l26 = *arg0
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l25, l26, 1);
+                }
+                _ => {
+                    let l27 = *arg0
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l28 = *arg0
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l27, l28, 1);
+                }
+            }
+        }
+        _ => {
+            let l29 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l29 {
+                0 => {
+                    let l30 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l31 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l30, l31, 1);
+                }
+                1 => {
+                    let l32 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l33 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l32, l33, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l34 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l35 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l34, l35, 1);
+                }
+                5 => {}
+                6 => {
+                    let l36 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l37 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l36, l37, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l38 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l39 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l38, l39, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_decode_split_with_options_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+    arg5: i32,
+    arg6: *mut u8,
+    arg7: usize,
+    arg8: *mut u8,
+    arg9: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let base3 = arg0;
+    let len3 = arg1;
+    let mut result3 = _rt::Vec::with_capacity(len3);
+    for i in 0..len3 {
+        let base = base3.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e3 = {
+            let l0 = *base.add(0).cast::<*mut u8>();
+            let l1 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len2 = l1;
+            _rt::Vec::from_raw_parts(l0.cast(), len2, len2)
+        };
+        result3.push(e3);
+    }
+    _rt::cabi_dealloc(
+        base3,
+        len3 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let base9 = arg8;
+    let len9 = arg9;
+    let mut result9 = _rt::Vec::with_capacity(len9);
+    for i in 0..len9 {
+        let base = base9.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+        let e9 = {
+            let l6 = *base.add(0).cast::<*mut u8>();
+            let l7 = *base.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+            let len8 = l7;
+            let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+            _rt::string_lift(bytes8)
+        };
+        result9.push(e9);
+    }
+    _rt::cabi_dealloc(
+        base9,
+        len9 * (2 * ::core::mem::size_of::<*const u8>()),
+        ::core::mem::size_of::<*const u8>(),
+    );
+    let result10 = T::decode_split_with_options(
+        result3,
+        ExtractOptions {
+            key: match arg2 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len4 = arg4;
+                        let bytes4 = _rt::Vec::from_raw_parts(arg3.cast(), len4, len4);
+                        _rt::string_lift(bytes4)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            password: match arg5 {
+                0 => None,
+                1 => {
+                    let e = {
+                        let len5 = arg7;
+                        let bytes5 = _rt::Vec::from_raw_parts(arg6.cast(), len5, len5);
+                        _rt::string_lift(bytes5)
+                    };
+                    Some(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            },
+            identities: result9,
+        },
+    );
+    let ptr11 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result10 {
+        Ok(e) => {
+            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+            let DecodedPayload {
+                content: content12,
+                signature: signature12,
+                corrected_errors: corrected_errors12,
+            } = e;
+            match content12 {
+                HiddenContent::Text(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                    let len13 = vec13.len();
+                    ::core::mem::forget(vec13);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr13.cast_mut();
+                }
+                HiddenContent::Binary(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec14 = (e).into_boxed_slice();
+                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                    let len14 = vec14.len();
+                    ::core::mem::forget(vec14);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len14;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr14.cast_mut();
+                }
+                HiddenContent::File(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let FilePayload {
+                        name: name15,
+                        media_type: media_type15,
+                        content: content15,
+                    } = e;
+                    let vec16 = (name15.into_bytes()).into_boxed_slice();
+                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                    let len16 = vec16.len();
+                    ::core::mem::forget(vec16);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len16;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr16.cast_mut();
+                    let vec17 = (media_type15.into_bytes()).into_boxed_slice();
+                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                    let len17 = vec17.len();
+                    ::core::mem::forget(vec17);
+                    *ptr11
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len17;
+                    *ptr11
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr17.cast_mut();
+                    let vec18 = (content15).into_boxed_slice();
+                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                    let len18 = vec18.len();
+                    ::core::mem::forget(vec18);
+                    *ptr11
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len18;
+                    *ptr11
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr18.cast_mut();
+                }
+                HiddenContent::Archive(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                    let vec23 = e;
+                    let len23 = vec23.len();
+                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec23.len() * (6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = if layout23.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout23);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec23.into_iter().enumerate() {
+                        let base = result23
+                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let FilePayload {
+                                name: name19,
+                                media_type: media_type19,
+                                content: content19,
+                            } = e;
+                            let vec20 = (name19.into_bytes()).into_boxed_slice();
+                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                            let len20 = vec20.len();
+                            ::core::mem::forget(vec20);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len20;
+                            *base.add(0).cast::<*mut u8>() = ptr20.cast_mut();
+                            let vec21 = (media_type19.into_bytes()).into_boxed_slice();
+                            let ptr21 = vec21.as_ptr().cast::<u8>();
+                            let len21 = vec21.len();
+                            ::core::mem::forget(vec21);
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len21;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr21.cast_mut();
+                            let vec22 = (content19).into_boxed_slice();
+                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                            let len22 = vec22.len();
+                            ::core::mem::forget(vec22);
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len22;
+                            *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr22.cast_mut();
+                        }
+                    }
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len23;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result23;
+                }
+            }
+            match signature12 {
+                SignatureStatus::Unsigned => {
+                    *ptr11.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                }
+                SignatureStatus::Valid(e) => {
+                    *ptr11.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                    let len24 = vec24.len();
+                    ::core::mem::forget(vec24);
+                    *ptr11
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len24;
+                    *ptr11
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr24.cast_mut();
+                }
+                SignatureStatus::Invalid(e) => {
+                    *ptr11.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                    let len25 = vec25.len();
+                    ::core::mem::forget(vec25);
+                    *ptr11
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len25;
+                    *ptr11
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr25.cast_mut();
+                }
+            }
+            *ptr11.add(11 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
+                corrected_errors12,
+            );
+        }
+        Err(e) => {
+            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
+                        as u8;
+                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                    let len26 = vec26.len();
+                    ::core::mem::forget(vec26);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len26;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr26.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
+                        as u8;
+                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (2i32)
+                        as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (3i32)
+                        as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (4i32)
+                        as u8;
+                    let vec28 = (e.into_bytes()).into_boxed_slice();
+                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                    let len28 = vec28.len();
+                    ::core::mem::forget(vec28);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len28;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr28.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (5i32)
+                        as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (6i32)
+                        as u8;
+                    let vec29 = (e.into_bytes()).into_boxed_slice();
+                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                    let len29 = vec29.len();
+                    ::core::mem::forget(vec29);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len29;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr29.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (7i32)
+                        as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (8i32)
+                        as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (9i32)
+                        as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (10i32)
+                        as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr11.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (11i32)
+                        as u8;
+                    let vec30 = (e.into_bytes()).into_boxed_slice();
+                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                    let len30 = vec30.len();
+                    ::core::mem::forget(vec30);
+                    *ptr11
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len30;
+                    *ptr11
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr30.cast_mut();
+                }
+            }
+        }
+    };
+    ptr11
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_decode_split_with_options<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base6 = l4;
+                    let len6 = l5;
+                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                }
+                2 => {
+                    let l7 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                    let l9 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l10 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                    let l11 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *arg0
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base13 = l11;
+                    let len13 = l12;
+                    _rt::cabi_dealloc(base13, len13 * 1, 1);
+                }
+                _ => {
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base23 = l14;
+                    let len23 = l15;
+                    for i in 0..len23 {
+                        let base = base23
+                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l16 = *base.add(0).cast::<*mut u8>();
+                            let l17 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l16, l17, 1);
+                            let l18 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l19 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l18, l19, 1);
+                            let l20 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l21 = *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l20;
+                            let len22 = l21;
+                            _rt::cabi_dealloc(base22, len22 * 1, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base23,
+                        len23 * (6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+            }
+            let l24 = i32::from(
+                *arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l24 {
+                0 => {}
+                1 => {
+                    let l25 = *arg0
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l26 = *arg0
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l25, l26, 1);
+                }
+                _ => {
+                    let l27 = *arg0
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l28 = *arg0
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l27, l28, 1);
+                }
+            }
+        }
+        _ => {
+            let l29 = i32::from(
+                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+            );
+            match l29 {
+                0 => {
+                    let l30 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l31 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l30, l31, 1);
+                }
+                1 => {
+                    let l32 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l33 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l32, l33, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l34 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l35 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l34, l35, 1);
+                }
+                5 => {}
+                6 => {
+                    let l36 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l37 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l36, l37, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l38 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l39 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l38, l39, 1);
+                }
+            }
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_generate_keypair_cabi<T: Guest>() -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let result0 = T::generate_keypair();
+    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let Keypair { public_key: public_key2, identity: identity2 } = result0;
+    let vec3 = (public_key2.into_bytes()).into_boxed_slice();
     let ptr3 = vec3.as_ptr().cast::<u8>();
     let len3 = vec3.len();
     ::core::mem::forget(vec3);
-    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
-    ptr2
+    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+    *ptr1.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+    let vec4 = (identity2.into_bytes()).into_boxed_slice();
+    let ptr4 = vec4.as_ptr().cast::<u8>();
+    let len4 = vec4.len();
+    ::core::mem::forget(vec4);
+    *ptr1.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+    *ptr1.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+        .cast_mut();
+    ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_decode_secret_from_jpeg<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_generate_keypair<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
+    _rt::cabi_dealloc(l0, l1, 1);
+    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    _rt::cabi_dealloc(l2, l3, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_generate_signing_keypair_cabi<T: Guest>() -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let result0 = T::generate_signing_keypair();
+    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+    let SigningKeypair { public_key: public_key2, signing_key: signing_key2 } = result0;
+    let vec3 = (public_key2.into_bytes()).into_boxed_slice();
+    let ptr3 = vec3.as_ptr().cast::<u8>();
+    let len3 = vec3.len();
+    ::core::mem::forget(vec3);
+    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+    *ptr1.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+    let vec4 = (signing_key2.into_bytes()).into_boxed_slice();
+    let ptr4 = vec4.as_ptr().cast::<u8>();
+    let len4 = vec4.len();
+    ::core::mem::forget(vec4);
+    *ptr1.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
+    *ptr1.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr4
+        .cast_mut();
+    ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_generate_signing_keypair<T: Guest>(arg0: *mut u8) {
     let l0 = *arg0.add(0).cast::<*mut u8>();
     let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
     _rt::cabi_dealloc(l0, l1, 1);
+    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
+    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
+    _rt::cabi_dealloc(l2, l3, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_probe_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let result1 = T::probe(_rt::Vec::from_raw_parts(arg0.cast(), len0, len0));
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let ProbeReport {
+                format: format3,
+                width: width3,
+                height: height3,
+                capacity: capacity3,
+                header: header3,
+            } = e;
+            *ptr2.add(8).cast::<u8>() = (format3.clone() as i32) as u8;
+            *ptr2.add(12).cast::<i32>() = _rt::as_i32(width3);
+            *ptr2.add(16).cast::<i32>() = _rt::as_i32(height3);
+            *ptr2.add(24).cast::<i64>() = _rt::as_i64(capacity3);
+            match header3 {
+                Some(e) => {
+                    *ptr2.add(32).cast::<u8>() = (1i32) as u8;
+                    let PayloadHeader {
+                        version: version4,
+                        flags: flags4,
+                        bits_per_channel: bits_per_channel4,
+                        length: length4,
+                    } = e;
+                    *ptr2.add(36).cast::<u8>() = (_rt::as_i32(version4)) as u8;
+                    let flags5 = flags4;
+                    *ptr2.add(38).cast::<u16>() = ((flags5.bits() >> 0) as i32) as u16;
+                    *ptr2.add(40).cast::<u8>() = (_rt::as_i32(bits_per_channel4)) as u8;
+                    *ptr2.add(44).cast::<i32>() = _rt::as_i32(length4);
+                }
+                None => {
+                    *ptr2.add(32).cast::<u8>() = (0i32) as u8;
+                }
+            };
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            match e {
+                StegoError::UnsupportedFormat(e) => {
+                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                StegoError::WrongPixelLayout(e) => {
+                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                StegoError::CapacityExceeded => {
+                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                }
+                StegoError::NoPayloadFound => {
+                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                }
+                StegoError::CorruptPayload(e) => {
+                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len8;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                StegoError::InvalidUtf8 => {
+                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                }
+                StegoError::InvalidOption(e) => {
+                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len9;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+                StegoError::PasswordRequired => {
+                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                }
+                StegoError::WrongPassword => {
+                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                }
+                StegoError::IdentityRequired => {
+                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                }
+                StegoError::NotARecipient => {
+                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                }
+                StegoError::IncompleteSplit(e) => {
+                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                    let len10 = vec10.len();
+                    ::core::mem::forget(vec10);
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len10;
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr10.cast_mut();
+                }
+            }
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_probe<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {}
+        _ => {
+            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+            match l1 {
+                0 => {
+                    let l2 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+                1 => {
+                    let l4 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l5 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l4, l5, 1);
+                }
+                2 => {}
+                3 => {}
+                4 => {
+                    let l6 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l7 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+                5 => {}
+                6 => {
+                    let l8 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l9 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l8, l9, 1);
+                }
+                7 => {}
+                8 => {}
+                9 => {}
+                10 => {}
+                _ => {
+                    let l10 = *arg0
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l11 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l10, l11, 1);
+                }
+            }
+        }
+    }
 }
 pub trait Guest {
-    fn encode_secret_into_bmp(secret: _rt::String, image: _rt::Vec<u8>) -> _rt::Vec<u8>;
-    fn decode_secret_from_bmp(image: _rt::Vec<u8>) -> _rt::String;
-    fn encode_secret_into_jpeg(secret: _rt::String, image: _rt::Vec<u8>) -> _rt::Vec<u8>;
-    fn decode_secret_from_jpeg(image: _rt::Vec<u8>) -> _rt::String;
+    fn encode_secret_into_bmp(
+        secret: _rt::String,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_secret_from_bmp(image: _rt::Vec<u8>) -> Result<_rt::String, StegoError>;
+    fn encode_bytes_into_bmp(
+        payload: _rt::Vec<u8>,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_bytes_from_bmp(image: _rt::Vec<u8>) -> Result<_rt::Vec<u8>, StegoError>;
+    /// How many payload bytes the BMP image can hold.
+    fn capacity_bmp(image: _rt::Vec<u8>) -> Result<u64, StegoError>;
+    fn encode_secret_into_jpeg(
+        secret: _rt::String,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_secret_from_jpeg(image: _rt::Vec<u8>) -> Result<_rt::String, StegoError>;
+    fn encode_bytes_into_jpeg(
+        payload: _rt::Vec<u8>,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_bytes_from_jpeg(image: _rt::Vec<u8>) -> Result<_rt::Vec<u8>, StegoError>;
+    /// How many payload bytes the JPEG image can hold once re-encoded.
+    fn capacity_jpeg(image: _rt::Vec<u8>) -> Result<u64, StegoError>;
+    fn encode_secret_into_png(
+        secret: _rt::String,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_secret_from_png(image: _rt::Vec<u8>) -> Result<_rt::String, StegoError>;
+    fn encode_bytes_into_png(
+        payload: _rt::Vec<u8>,
+        image: _rt::Vec<u8>,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn decode_bytes_from_png(image: _rt::Vec<u8>) -> Result<_rt::Vec<u8>, StegoError>;
+    /// How many payload bytes the PNG image can hold.
+    fn capacity_png(image: _rt::Vec<u8>) -> Result<u64, StegoError>;
+    /// Embeds into a BMP, PNG or JPEG image, detected from its content.
+    fn encode_secret_with_options(
+        secret: _rt::String,
+        image: _rt::Vec<u8>,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    fn encode_bytes_with_options(
+        payload: _rt::Vec<u8>,
+        image: _rt::Vec<u8>,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    /// Embeds a file with its name and media type, which take a few bytes of the capacity.
+    fn encode_file_with_options(
+        file: FilePayload,
+        image: _rt::Vec<u8>,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    /// Embeds several files, with unique names, as a single archive.
+    fn encode_archive_with_options(
+        files: _rt::Vec<FilePayload>,
+        image: _rt::Vec<u8>,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<u8>, StegoError>;
+    /// Splits the content across every image, in proportion to their capacities, and returns them in the same order.
+    /// Each one takes 12 bytes of its capacity for its chunk header on top of the options.
+    fn encode_split_with_options(
+        content: HiddenContent,
+        images: _rt::Vec<_rt::Vec<u8>>,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<_rt::Vec<u8>>, StegoError>;
+    /// Shares the content across every image, so any `threshold` of them recover it and fewer reveal nothing of it but
+    /// its length, and returns them in the same order. Each one holds the whole sealed content plus 10 bytes of share header.
+    fn encode_shared_with_options(
+        content: HiddenContent,
+        images: _rt::Vec<_rt::Vec<u8>>,
+        threshold: u8,
+        options: EmbedOptions,
+    ) -> Result<_rt::Vec<_rt::Vec<u8>>, StegoError>;
+    /// How many payload bytes the image can hold with these options, compressible payloads can be longer.
+    fn capacity_with_options(
+        image: _rt::Vec<u8>,
+        options: EmbedOptions,
+    ) -> Result<u64, StegoError>;
+    /// Extracts from a BMP, PNG or JPEG image, detected from its content, and checks its signature.
+    /// The exports without options don't report the signature.
+    fn decode_secret_with_options(
+        image: _rt::Vec<u8>,
+        options: ExtractOptions,
+    ) -> Result<DecodedSecret, StegoError>;
+    fn decode_bytes_with_options(
+        image: _rt::Vec<u8>,
+        options: ExtractOptions,
+    ) -> Result<DecodedBytes, StegoError>;
+    /// Extracts text, bytes, a file or an archive, whichever was embedded. The other two hand over the content of a file
+    /// and an archive as embedded.
+    fn decode_with_options(
+        image: _rt::Vec<u8>,
+        options: ExtractOptions,
+    ) -> Result<DecodedPayload, StegoError>;
+    /// Joins back a payload split across these images, or recovers one shared across them, given in any order.
+    fn decode_split_with_options(
+        images: _rt::Vec<_rt::Vec<u8>>,
+        options: ExtractOptions,
+    ) -> Result<DecodedPayload, StegoError>;
+    /// New key pair, its public key can be given as an embedding recipient.
+    fn generate_keypair() -> Keypair;
+    /// New signing key pair, its signing key can be given as an embedding option.
+    fn generate_signing_keypair() -> SigningKeypair;
+    /// Detects the carrier format and looks for a payload header without extracting the payload.
+    /// Payloads embedded with a key aren't found.
+    fn probe(image: _rt::Vec<u8>) -> Result<ProbeReport, StegoError>;
 }
 #[doc(hidden)]
 macro_rules! __export_world_steganography_cabi {
@@ -164,7 +8146,26 @@ macro_rules! __export_world_steganography_cabi {
         arg1) } } #[unsafe (export_name = "cabi_post_decode-secret-from-bmp")] unsafe
         extern "C" fn _post_return_decode_secret_from_bmp(arg0 : * mut u8,) { unsafe {
         $($path_to_types)*:: __post_return_decode_secret_from_bmp::<$ty > (arg0) } }
-        #[unsafe (export_name = "encode-secret-into-jpeg")] unsafe extern "C" fn
+        #[unsafe (export_name = "encode-bytes-into-bmp")] unsafe extern "C" fn
+        export_encode_bytes_into_bmp(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3
+        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_encode_bytes_into_bmp_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe
+        (export_name = "cabi_post_encode-bytes-into-bmp")] unsafe extern "C" fn
+        _post_return_encode_bytes_into_bmp(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_bytes_into_bmp::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-bytes-from-bmp")] unsafe extern "C" fn
+        export_decode_bytes_from_bmp(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe
+        { $($path_to_types)*:: _export_decode_bytes_from_bmp_cabi::<$ty > (arg0, arg1) }
+        } #[unsafe (export_name = "cabi_post_decode-bytes-from-bmp")] unsafe extern "C"
+        fn _post_return_decode_bytes_from_bmp(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_bytes_from_bmp::<$ty > (arg0) } }
+        #[unsafe (export_name = "capacity-bmp")] unsafe extern "C" fn
+        export_capacity_bmp(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_capacity_bmp_cabi::<$ty > (arg0, arg1) } } #[unsafe
+        (export_name = "cabi_post_capacity-bmp")] unsafe extern "C" fn
+        _post_return_capacity_bmp(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_capacity_bmp::<$ty > (arg0) } } #[unsafe (export_name =
+        "encode-secret-into-jpeg")] unsafe extern "C" fn
         export_encode_secret_into_jpeg(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8,
         arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
         _export_encode_secret_into_jpeg_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
@@ -176,25 +8177,173 @@ macro_rules! __export_world_steganography_cabi {
         unsafe { $($path_to_types)*:: _export_decode_secret_from_jpeg_cabi::<$ty > (arg0,
         arg1) } } #[unsafe (export_name = "cabi_post_decode-secret-from-jpeg")] unsafe
         extern "C" fn _post_return_decode_secret_from_jpeg(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_decode_secret_from_jpeg::<$ty > (arg0) } } };
+        $($path_to_types)*:: __post_return_decode_secret_from_jpeg::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-bytes-into-jpeg")] unsafe extern "C" fn
+        export_encode_bytes_into_jpeg(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8,
+        arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_encode_bytes_into_jpeg_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe
+        (export_name = "cabi_post_encode-bytes-into-jpeg")] unsafe extern "C" fn
+        _post_return_encode_bytes_into_jpeg(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_bytes_into_jpeg::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-bytes-from-jpeg")] unsafe extern "C" fn
+        export_decode_bytes_from_jpeg(arg0 : * mut u8, arg1 : usize,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_decode_bytes_from_jpeg_cabi::<$ty > (arg0,
+        arg1) } } #[unsafe (export_name = "cabi_post_decode-bytes-from-jpeg")] unsafe
+        extern "C" fn _post_return_decode_bytes_from_jpeg(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_bytes_from_jpeg::<$ty > (arg0) } }
+        #[unsafe (export_name = "capacity-jpeg")] unsafe extern "C" fn
+        export_capacity_jpeg(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_capacity_jpeg_cabi::<$ty > (arg0, arg1) } } #[unsafe
+        (export_name = "cabi_post_capacity-jpeg")] unsafe extern "C" fn
+        _post_return_capacity_jpeg(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_capacity_jpeg::<$ty > (arg0) } } #[unsafe (export_name =
+        "encode-secret-into-png")] unsafe extern "C" fn
+        export_encode_secret_into_png(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8,
+        arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_encode_secret_into_png_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe
+        (export_name = "cabi_post_encode-secret-into-png")] unsafe extern "C" fn
+        _post_return_encode_secret_into_png(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_secret_into_png::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-secret-from-png")] unsafe extern "C" fn
+        export_decode_secret_from_png(arg0 : * mut u8, arg1 : usize,) -> * mut u8 {
+        unsafe { $($path_to_types)*:: _export_decode_secret_from_png_cabi::<$ty > (arg0,
+        arg1) } } #[unsafe (export_name = "cabi_post_decode-secret-from-png")] unsafe
+        extern "C" fn _post_return_decode_secret_from_png(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_secret_from_png::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-bytes-into-png")] unsafe extern "C" fn
+        export_encode_bytes_into_png(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3
+        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_encode_bytes_into_png_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe
+        (export_name = "cabi_post_encode-bytes-into-png")] unsafe extern "C" fn
+        _post_return_encode_bytes_into_png(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_bytes_into_png::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-bytes-from-png")] unsafe extern "C" fn
+        export_decode_bytes_from_png(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe
+        { $($path_to_types)*:: _export_decode_bytes_from_png_cabi::<$ty > (arg0, arg1) }
+        } #[unsafe (export_name = "cabi_post_decode-bytes-from-png")] unsafe extern "C"
+        fn _post_return_decode_bytes_from_png(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_bytes_from_png::<$ty > (arg0) } }
+        #[unsafe (export_name = "capacity-png")] unsafe extern "C" fn
+        export_capacity_png(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_capacity_png_cabi::<$ty > (arg0, arg1) } } #[unsafe
+        (export_name = "cabi_post_capacity-png")] unsafe extern "C" fn
+        _post_return_capacity_png(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_capacity_png::<$ty > (arg0) } } #[unsafe (export_name =
+        "encode-secret-with-options")] unsafe extern "C" fn
+        export_encode_secret_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_secret_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-secret-with-options")] unsafe extern
+        "C" fn _post_return_encode_secret_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_secret_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-bytes-with-options")] unsafe extern "C" fn
+        export_encode_bytes_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_bytes_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-bytes-with-options")] unsafe extern "C"
+        fn _post_return_encode_bytes_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_bytes_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-file-with-options")] unsafe extern "C" fn
+        export_encode_file_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_file_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-file-with-options")] unsafe extern "C"
+        fn _post_return_encode_file_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_file_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-archive-with-options")] unsafe extern "C" fn
+        export_encode_archive_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_archive_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-archive-with-options")] unsafe extern
+        "C" fn _post_return_encode_archive_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_archive_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-split-with-options")] unsafe extern "C" fn
+        export_encode_split_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_split_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-split-with-options")] unsafe extern "C"
+        fn _post_return_encode_split_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_split_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "encode-shared-with-options")] unsafe extern "C" fn
+        export_encode_shared_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_encode_shared_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_encode-shared-with-options")] unsafe extern
+        "C" fn _post_return_encode_shared_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_encode_shared_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "capacity-with-options")] unsafe extern "C" fn
+        export_capacity_with_options(arg0 : * mut u8,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_capacity_with_options_cabi::<$ty > (arg0) } }
+        #[unsafe (export_name = "cabi_post_capacity-with-options")] unsafe extern "C" fn
+        _post_return_capacity_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_capacity_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-secret-with-options")] unsafe extern "C" fn
+        export_decode_secret_with_options(arg0 : * mut u8, arg1 : usize, arg2 : i32, arg3
+        : * mut u8, arg4 : usize, arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut
+        u8, arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_decode_secret_with_options_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+        arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+        "cabi_post_decode-secret-with-options")] unsafe extern "C" fn
+        _post_return_decode_secret_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_secret_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-bytes-with-options")] unsafe extern "C" fn
+        export_decode_bytes_with_options(arg0 : * mut u8, arg1 : usize, arg2 : i32, arg3
+        : * mut u8, arg4 : usize, arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut
+        u8, arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_decode_bytes_with_options_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+        arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+        "cabi_post_decode-bytes-with-options")] unsafe extern "C" fn
+        _post_return_decode_bytes_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_bytes_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-with-options")] unsafe extern "C" fn
+        export_decode_with_options(arg0 : * mut u8, arg1 : usize, arg2 : i32, arg3 : *
+        mut u8, arg4 : usize, arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut u8,
+        arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_decode_with_options_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5,
+        arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+        "cabi_post_decode-with-options")] unsafe extern "C" fn
+        _post_return_decode_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "decode-split-with-options")] unsafe extern "C" fn
+        export_decode_split_with_options(arg0 : * mut u8, arg1 : usize, arg2 : i32, arg3
+        : * mut u8, arg4 : usize, arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut
+        u8, arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_decode_split_with_options_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+        arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+        "cabi_post_decode-split-with-options")] unsafe extern "C" fn
+        _post_return_decode_split_with_options(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_decode_split_with_options::<$ty > (arg0) } }
+        #[unsafe (export_name = "generate-keypair")] unsafe extern "C" fn
+        export_generate_keypair() -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_generate_keypair_cabi::<$ty > () } } #[unsafe (export_name =
+        "cabi_post_generate-keypair")] unsafe extern "C" fn
+        _post_return_generate_keypair(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_generate_keypair::<$ty > (arg0) } } #[unsafe (export_name =
+        "generate-signing-keypair")] unsafe extern "C" fn
+        export_generate_signing_keypair() -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_generate_signing_keypair_cabi::<$ty > () } } #[unsafe (export_name =
+        "cabi_post_generate-signing-keypair")] unsafe extern "C" fn
+        _post_return_generate_signing_keypair(arg0 : * mut u8,) { unsafe {
+        $($path_to_types)*:: __post_return_generate_signing_keypair::<$ty > (arg0) } }
+        #[unsafe (export_name = "probe")] unsafe extern "C" fn export_probe(arg0 : * mut
+        u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+        _export_probe_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
+        "cabi_post_probe")] unsafe extern "C" fn _post_return_probe(arg0 : * mut u8,) {
+        unsafe { $($path_to_types)*:: __post_return_probe::<$ty > (arg0) } } };
     };
 }
 #[doc(hidden)]
 pub(crate) use __export_world_steganography_cabi;
-#[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-#[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()]);
+#[repr(align(8))]
+struct _RetArea(
+    [::core::mem::MaybeUninit<u8>; 12 * ::core::mem::size_of::<*const u8>()],
+);
 static mut _RET_AREA: _RetArea = _RetArea(
-    [::core::mem::MaybeUninit::uninit(); 2 * ::core::mem::size_of::<*const u8>()],
+    [::core::mem::MaybeUninit::uninit(); 12 * ::core::mem::size_of::<*const u8>()],
 );
 #[rustfmt::skip]
 mod _rt {
     #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
-    pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
             String::from_utf8(bytes).unwrap()
@@ -209,9 +8358,97 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    pub use alloc_crate::string::String;
-    extern crate alloc as alloc_crate;
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub use alloc_crate::alloc;
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -248,14 +8485,58 @@ pub(crate) use __export_steganography_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 343] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xd3\x01\x01A\x02\x01\
-A\x09\x01@\x01\x07messages\x01\0\x03\0\x03log\x01\0\x01p}\x01@\x02\x06secrets\x05\
-image\x01\0\x01\x04\0\x16encode-secret-into-bmp\x01\x02\x01@\x01\x05image\x01\0s\
-\x04\0\x16decode-secret-from-bmp\x01\x03\x04\0\x17encode-secret-into-jpeg\x01\x02\
-\x04\0\x17decode-secret-from-jpeg\x01\x03\x04\0!local:steganography/steganograph\
-y\x04\0\x0b\x13\x01\0\x0dsteganography\x03\0\0\0G\x09producers\x01\x0cprocessed-\
-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2575] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x8b\x13\x01A\x02\x01\
+Ab\x01q\x0c\x12unsupported-format\x01s\0\x12wrong-pixel-layout\x01s\0\x11capacit\
+y-exceeded\0\0\x10no-payload-found\0\0\x0fcorrupt-payload\x01s\0\x0cinvalid-utf8\
+\0\0\x0einvalid-option\x01s\0\x11password-required\0\0\x0ewrong-password\0\0\x11\
+identity-required\0\0\x0fnot-a-recipient\0\0\x10incomplete-split\x01s\0\x03\0\x0b\
+stego-error\x03\0\0\x01r\x02\x07messages\x08passwords\x03\0\x05decoy\x03\0\x02\x01\
+k}\x01ks\x01ps\x01k\x03\x01r\x08\x10bits-per-channel}\x07quality\x04\x03key\x05\x08\
+password\x05\x0arecipients\x06\x0bsigning-key\x05\x10error-correction}\x05decoy\x07\
+\x03\0\x0dembed-options\x03\0\x08\x01r\x03\x03key\x05\x08password\x05\x0aidentit\
+ies\x06\x03\0\x0fextract-options\x03\0\x0a\x01r\x02\x0apublic-keys\x08identitys\x03\
+\0\x07keypair\x03\0\x0c\x01r\x02\x0apublic-keys\x0bsigning-keys\x03\0\x0fsigning\
+-keypair\x03\0\x0e\x01q\x03\x08unsigned\0\0\x05valid\x01s\0\x07invalid\x01s\0\x03\
+\0\x10signature-status\x03\0\x10\x01r\x03\x06secrets\x09signature\x11\x10correct\
+ed-errorsy\x03\0\x0edecoded-secret\x03\0\x12\x01p}\x01r\x03\x07payload\x14\x09si\
+gnature\x11\x10corrected-errorsy\x03\0\x0ddecoded-bytes\x03\0\x15\x01r\x03\x04na\
+mes\x0amedia-types\x07content\x14\x03\0\x0cfile-payload\x03\0\x17\x01p\x18\x01q\x04\
+\x04text\x01s\0\x06binary\x01\x14\0\x04file\x01\x18\0\x07archive\x01\x19\0\x03\0\
+\x0ehidden-content\x03\0\x1a\x01r\x03\x07content\x1b\x09signature\x11\x10correct\
+ed-errorsy\x03\0\x0fdecoded-payload\x03\0\x1c\x01m\x03\x03bmp\x04jpeg\x03png\x03\
+\0\x0ecarrier-format\x03\0\x1e\x01n\x0a\x0acompressed\x09encrypted\x06binary\x0a\
+recipients\x06signed\x0ferror-corrected\x04file\x07archive\x05split\x06shared\x03\
+\0\x0dpayload-flags\x03\0\x20\x01r\x04\x07version}\x05flags!\x10bits-per-channel\
+}\x06lengthy\x03\0\x0epayload-header\x03\0\"\x01k#\x01r\x05\x06format\x1f\x05wid\
+thy\x06heighty\x08capacityw\x06header$\x03\0\x0cprobe-report\x03\0%\x01@\x01\x07\
+messages\x01\0\x03\0\x03log\x01'\x01j\x01\x14\x01\x01\x01@\x02\x06secrets\x05ima\
+ge\x14\0(\x04\0\x16encode-secret-into-bmp\x01)\x01j\x01s\x01\x01\x01@\x01\x05ima\
+ge\x14\0*\x04\0\x16decode-secret-from-bmp\x01+\x01@\x02\x07payload\x14\x05image\x14\
+\0(\x04\0\x15encode-bytes-into-bmp\x01,\x01@\x01\x05image\x14\0(\x04\0\x15decode\
+-bytes-from-bmp\x01-\x01j\x01w\x01\x01\x01@\x01\x05image\x14\0.\x04\0\x0ccapacit\
+y-bmp\x01/\x04\0\x17encode-secret-into-jpeg\x01)\x04\0\x17decode-secret-from-jpe\
+g\x01+\x04\0\x16encode-bytes-into-jpeg\x01,\x04\0\x16decode-bytes-from-jpeg\x01-\
+\x04\0\x0dcapacity-jpeg\x01/\x04\0\x16encode-secret-into-png\x01)\x04\0\x16decod\
+e-secret-from-png\x01+\x04\0\x15encode-bytes-into-png\x01,\x04\0\x15decode-bytes\
+-from-png\x01-\x04\0\x0ccapacity-png\x01/\x01@\x03\x06secrets\x05image\x14\x07op\
+tions\x09\0(\x04\0\x1aencode-secret-with-options\x010\x01@\x03\x07payload\x14\x05\
+image\x14\x07options\x09\0(\x04\0\x19encode-bytes-with-options\x011\x01@\x03\x04\
+file\x18\x05image\x14\x07options\x09\0(\x04\0\x18encode-file-with-options\x012\x01\
+@\x03\x05files\x19\x05image\x14\x07options\x09\0(\x04\0\x1bencode-archive-with-o\
+ptions\x013\x01p\x14\x01j\x014\x01\x01\x01@\x03\x07content\x1b\x06images4\x07opt\
+ions\x09\05\x04\0\x19encode-split-with-options\x016\x01@\x04\x07content\x1b\x06i\
+mages4\x09threshold}\x07options\x09\05\x04\0\x1aencode-shared-with-options\x017\x01\
+@\x02\x05image\x14\x07options\x09\0.\x04\0\x15capacity-with-options\x018\x01j\x01\
+\x13\x01\x01\x01@\x02\x05image\x14\x07options\x0b\09\x04\0\x1adecode-secret-with\
+-options\x01:\x01j\x01\x16\x01\x01\x01@\x02\x05image\x14\x07options\x0b\0;\x04\0\
+\x19decode-bytes-with-options\x01<\x01j\x01\x1d\x01\x01\x01@\x02\x05image\x14\x07\
+options\x0b\0=\x04\0\x13decode-with-options\x01>\x01@\x02\x06images4\x07options\x0b\
+\0=\x04\0\x19decode-split-with-options\x01?\x01@\0\0\x0d\x04\0\x10generate-keypa\
+ir\x01@\x01@\0\0\x0f\x04\0\x18generate-signing-keypair\x01A\x01j\x01&\x01\x01\x01\
+@\x01\x05image\x14\0\xc2\0\x04\0\x05probe\x01C\x04\0!local:steganography/stegano\
+graphy\x04\0\x0b\x13\x01\0\x0dsteganography\x03\0\0\0G\x09producers\x01\x0cproce\
+ssed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
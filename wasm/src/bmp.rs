@@ -0,0 +1,59 @@
+use std::io::Cursor;
+use image::DynamicImage;
+use image::codecs::bmp::BmpDecoder;
+
+use crate::{CarrierFormat, EmbedOptions, ExtractOptions, PayloadFlags, ProbeReport, StegoError};
+use crate::container::{self, Header};
+use crate::decoy::HiddenSlot;
+use crate::lsb;
+
+/// Hides `payload` in the least significant bits of a BMP and returns the new BMP.
+///
+/// 24 bit, 32 bit with alpha and grayscale BMPs are written back with the same layout.
+pub(crate) fn embed(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, hidden: Option<&HiddenSlot>, image: &[u8]) -> Result<Vec<u8>, StegoError> {
+    let mut img = read_bmp(image)?;
+    lsb::embed(&mut img, payload, flags, options.bits_per_channel, options.key.as_deref(), hidden)?;
+
+    let mut ret = Vec::<u8>::new();
+    let mut writer = Cursor::new(&mut ret);
+    img.write_to(&mut writer, image::ImageFormat::Bmp)?;
+
+    Ok(ret)
+}
+
+/// Reads back the header and the payload hidden by [`embed`].
+pub(crate) fn extract(image: &[u8], options: &ExtractOptions) -> Result<(Header, Vec<u8>), StegoError> {
+    lsb::extract(&read_bmp(image)?, options.key.as_deref())
+}
+
+/// Reads back the slot [`embed`] hid behind a decoy, if `password` is its own.
+pub(crate) fn extract_hidden(image: &[u8], options: &ExtractOptions, password: &str) -> Result<(Header, Vec<u8>), StegoError> {
+    lsb::extract_hidden(&read_bmp(image)?, options.key.as_deref(), password)
+}
+
+/// How many payload bytes [`embed`] can hide in the image.
+pub(crate) fn capacity(image: &[u8], options: &EmbedOptions) -> Result<u64, StegoError> {
+    lsb::capacity(&read_bmp(image)?, options.bits_per_channel, container::header_len(options.error_correction > 0))
+}
+
+/// Describes the image and the header of the payload it hides, if any, without reading the payload.
+pub(crate) fn probe(image: &[u8]) -> Result<ProbeReport, StegoError> {
+    lsb::probe(&read_bmp(image)?, CarrierFormat::Bmp)
+}
+
+/// Decodes the BMP into the pixel type it is written back from.
+///
+/// The decoder expands every palette to RGB, so BMPs whose palette only holds grays are
+/// turned into grayscale images to keep them 8 bit grayscale on output.
+fn read_bmp(image: &[u8]) -> Result<DynamicImage, StegoError> {
+    let decoder = BmpDecoder::new(Cursor::new(image))?;
+    let grayscale = decoder.get_palette()
+        .is_some_and(|palette| palette.iter().all(|&[r, g, b]| r == g && g == b));
+    let image = DynamicImage::from_decoder(decoder)?;
+
+    if grayscale {
+        Ok(DynamicImage::ImageLuma8(image.to_luma8()))
+    } else {
+        Ok(image)
+    }
+}
@@ -0,0 +1,29 @@
+use miniz_oxide::deflate::{compress_to_vec, CompressionLevel};
+use miniz_oxide::inflate::{decompress_to_vec_with_limit, TINFLStatus};
+
+use crate::StegoError;
+
+/// Most bytes a compressed payload may inflate to. Larger payloads are stored as they are, so
+/// anything claiming to inflate further is a decompression bomb.
+pub(crate) const MAX_DECOMPRESSED_LEN: usize = 16 * 1024 * 1024;
+
+/// Raw DEFLATE stream of `payload`, or `None` when it doesn't come out any smaller and should be
+/// stored as it is.
+pub(crate) fn compress(payload: &[u8]) -> Option<Vec<u8>> {
+    if payload.len() > MAX_DECOMPRESSED_LEN {
+        return None;
+    }
+
+    // payloads are as small as the carriers, the slowest level costs next to nothing
+    let compressed = compress_to_vec(payload, CompressionLevel::BestCompression as u8);
+
+    (compressed.len() < payload.len()).then_some(compressed)
+}
+
+/// Inflates what [`compress`] returned, stopping at [`MAX_DECOMPRESSED_LEN`] bytes.
+pub(crate) fn decompress(compressed: &[u8]) -> Result<Vec<u8>, StegoError> {
+    decompress_to_vec_with_limit(compressed, MAX_DECOMPRESSED_LEN).map_err(|err| match err.status {
+        TINFLStatus::HasMoreOutput => StegoError::CorruptPayload(format!("the payload inflates past {MAX_DECOMPRESSED_LEN} bytes")),
+        _ => StegoError::CorruptPayload("the payload isn't valid DEFLATE data".to_string()),
+    })
+}
@@ -0,0 +1,215 @@
+use crate::{PayloadFlags, PayloadHeader, StegoError};
+
+/// Marks the start of every hidden payload.
+const MAGIC: [u8; 4] = *b"WSTG";
+
+/// Latest layout version written by this crate. Readers reject newer versions.
+///
+/// Version 1 LSB carriers spread every payload byte over 9 samples, version 2 packs it densely
+/// with the bits per channel stored in the header. Version 3 keeps the flags past the eighth in
+/// the byte reserved until then, it's only written when one of them is set so everything else
+/// stays readable by version 2 readers. Version 4 stores the header of error corrected payloads
+/// [`HEADER_COPIES`] times, it's only written for those.
+const VERSION: u8 = 4;
+
+/// Version of the headers stored several times, in front of error corrected payloads.
+const PROTECTED_VERSION: u8 = 4;
+
+/// Bytes taken by a single copy of the header.
+pub(crate) const HEADER_LEN: usize = 16;
+
+/// Copies of the header in front of error corrected payloads, so damage to one is outvoted bit by
+/// bit as long as the other two agree.
+const HEADER_COPIES: usize = 3;
+
+/// Header stored in front of every payload, in both BMP and JPEG carriers.
+///
+/// Layout, little endian:
+///
+/// | bytes  | field                                |
+/// |--------|--------------------------------------|
+/// | 0..4   | magic `WSTG`                         |
+/// | 4      | version                              |
+/// | 5      | flags 1 to 8                         |
+/// | 6      | LSB bits per channel                 |
+/// | 7      | flags 9 to 16, zero before version 3 |
+/// | 8..12  | payload length                       |
+/// | 12..16 | CRC32 of the payload                 |
+///
+/// Error corrected payloads have it [`HEADER_COPIES`] times in a row, as their parity doesn't
+/// cover it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Header {
+    pub(crate) version: u8,
+    pub(crate) flags: PayloadFlags,
+    /// Only used by LSB carriers, always zero otherwise and in version 1 headers.
+    pub(crate) bits_per_channel: u8,
+    pub(crate) length: u32,
+    pub(crate) crc32: u32,
+}
+
+impl Header {
+    /// Header of `payload` in the current layout version.
+    pub(crate) fn new(payload: &[u8], flags: PayloadFlags, bits_per_channel: u8) -> Result<Header, StegoError> {
+        Ok(Header {
+            version: if flags.contains(PayloadFlags::ERROR_CORRECTED) {
+                PROTECTED_VERSION
+            } else if flags.bits() > 0xFF {
+                3
+            } else {
+                2
+            },
+            flags,
+            bits_per_channel,
+            length: u32::try_from(payload.len()).map_err(|_| StegoError::CapacityExceeded)?,
+            crc32: crc32fast::hash(payload),
+        })
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; HEADER_LEN] {
+        let mut bytes = [0u8; HEADER_LEN];
+
+        bytes[0..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.version;
+        [bytes[5], bytes[7]] = self.flags.bits().to_le_bytes();
+        bytes[6] = self.bits_per_channel;
+        bytes[8..12].copy_from_slice(&self.length.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.crc32.to_le_bytes());
+
+        bytes
+    }
+
+    /// Bytes the header takes in front of its payload, all of its copies included.
+    pub(crate) fn stored_len(&self) -> usize {
+        header_len(self.flags.contains(PayloadFlags::ERROR_CORRECTED))
+    }
+
+    /// The header as stored in front of its payload, several times if that is error corrected.
+    pub(crate) fn to_stored_bytes(self) -> Vec<u8> {
+        self.to_bytes().repeat(self.stored_len() / HEADER_LEN)
+    }
+
+    fn parse(bytes: &[u8; HEADER_LEN]) -> Result<Header, StegoError> {
+        if bytes[0..4] != MAGIC {
+            return Err(StegoError::NoPayloadFound);
+        }
+
+        let version = bytes[4];
+
+        if version > VERSION {
+            return Err(StegoError::CorruptPayload(format!("unsupported container version {version}")));
+        }
+
+        let flags = u16::from_le_bytes([bytes[5], if version < 3 { 0 } else { bytes[7] }]);
+        let flags = PayloadFlags::from_bits(flags)
+            .ok_or_else(|| StegoError::CorruptPayload(format!("unknown payload flags {flags:#06x}")))?;
+
+        Ok(Header {
+            version,
+            flags,
+            bits_per_channel: if version == 1 { 0 } else { bytes[6] },
+            length: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+            crc32: u32::from_le_bytes(bytes[12..16].try_into().unwrap()),
+        })
+    }
+}
+
+impl From<Header> for PayloadHeader {
+    fn from(header: Header) -> Self {
+        PayloadHeader {
+            version: header.version,
+            flags: header.flags,
+            bits_per_channel: header.bits_per_channel,
+            length: header.length,
+        }
+    }
+}
+
+/// Bytes the header of a payload takes, whether the payload is `error_corrected` or not.
+pub(crate) fn header_len(error_corrected: bool) -> usize {
+    if error_corrected { HEADER_LEN * HEADER_COPIES } else { HEADER_LEN }
+}
+
+/// Prepends the header to `payload`, giving the bytes a carrier hides.
+pub(crate) fn seal(payload: &[u8], flags: PayloadFlags) -> Result<Vec<u8>, StegoError> {
+    let header = Header::new(payload, flags, 0)?;
+
+    let mut sealed = Vec::with_capacity(header.stored_len() + payload.len());
+    sealed.extend_from_slice(&header.to_stored_bytes());
+    sealed.extend_from_slice(payload);
+
+    Ok(sealed)
+}
+
+/// Reads the header and then the payload it announces out of the bytes hidden in a carrier.
+///
+/// Only the announced bytes are pulled from `hidden`, so carriers can hand out a lazy iterator.
+pub(crate) fn unseal(mut hidden: impl Iterator<Item = u8>) -> Result<(Header, Vec<u8>), StegoError> {
+    let header = read_header(&mut hidden)?;
+    let payload = read_payload(&header, hidden)?;
+
+    Ok((header, payload))
+}
+
+/// Reads the payload announced by `header` out of the bytes hidden after it.
+///
+/// The payload is returned as stored, it's up to the caller to undo what its flags say.
+pub(crate) fn read_payload(header: &Header, hidden: impl Iterator<Item = u8>) -> Result<Vec<u8>, StegoError> {
+    let payload: Vec<u8> = hidden.take(header.length as usize).collect();
+
+    if payload.len() != header.length as usize {
+        return Err(StegoError::CorruptPayload(format!("expected {} bytes but the image only holds {}", header.length, payload.len())));
+    }
+
+    // damage to error corrected payloads is only told once they are corrected
+    if !header.flags.contains(PayloadFlags::ERROR_CORRECTED) {
+        check_crc(header, &payload)?;
+    }
+
+    Ok(payload)
+}
+
+/// Fails if `payload` isn't the one `header` was made for.
+pub(crate) fn check_crc(header: &Header, payload: &[u8]) -> Result<(), StegoError> {
+    if crc32fast::hash(payload) != header.crc32 {
+        return Err(StegoError::CorruptPayload("checksum mismatch".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Reads only the header out of the bytes hidden in a carrier, `None` if there's no valid one.
+pub(crate) fn peek(mut hidden: impl Iterator<Item = u8>) -> Option<Header> {
+    read_header(&mut hidden).ok()
+}
+
+/// Reads the header out of the first bytes hidden in a carrier, outvoting damage to a copy of
+/// it if it's stored several times.
+pub(crate) fn read_header(hidden: &mut impl Iterator<Item = u8>) -> Result<Header, StegoError> {
+    let first = read_copy(hidden).ok_or(StegoError::NoPayloadFound)?;
+
+    // either tells there are more copies, a single damaged bit can't clear both
+    let protected = first[4] == PROTECTED_VERSION || first[5] & PayloadFlags::ERROR_CORRECTED.bits() as u8 != 0;
+
+    if !protected {
+        return Header::parse(&first);
+    }
+
+    let (Some(second), Some(third)) = (read_copy(hidden), read_copy(hidden)) else {
+        return Header::parse(&first);
+    };
+    let voted: [u8; HEADER_LEN] = std::array::from_fn(|i| first[i] & second[i] | first[i] & third[i] | second[i] & third[i]);
+
+    Header::parse(&voted)
+}
+
+/// Next [`HEADER_LEN`] bytes of `hidden`, `None` if it runs out before.
+fn read_copy(hidden: &mut impl Iterator<Item = u8>) -> Option<[u8; HEADER_LEN]> {
+    let mut bytes = [0u8; HEADER_LEN];
+
+    for byte in bytes.iter_mut() {
+        *byte = hidden.next()?;
+    }
+
+    Some(bytes)
+}
@@ -0,0 +1,78 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+
+use crate::StegoError;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+
+/// Bytes encryption adds to a payload: the salt and nonce in front of it and the tag after it.
+pub(crate) const OVERHEAD: usize = SALT_LEN + NONCE_LEN + TAG_LEN;
+
+/// Argon2id memory cost in KiB, with the time and parallelism costs below it's the minimum
+/// recommended by OWASP. Changing any of them breaks every payload encrypted before.
+const ARGON2_MEMORY_KIB: u32 = 19 * 1024;
+const ARGON2_ITERATIONS: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+
+/// Encrypts `plaintext` with XChaCha20-Poly1305 under a key derived from `password` with Argon2id.
+///
+/// Layout of the result:
+///
+/// | bytes          | field                          |
+/// |----------------|--------------------------------|
+/// | 0..16          | Argon2id salt                  |
+/// | 16..40         | XChaCha20-Poly1305 nonce       |
+/// | 40..           | ciphertext and 16 byte tag     |
+pub(crate) fn encrypt(plaintext: &[u8], password: &str) -> Result<Vec<u8>, StegoError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let cipher = XChaCha20Poly1305::new(&derive_key(password, &salt)?);
+    let ciphertext = cipher.encrypt(&nonce, plaintext)
+        .map_err(|_| StegoError::CapacityExceeded)?;
+
+    let mut encrypted = Vec::with_capacity(OVERHEAD + plaintext.len());
+    encrypted.extend_from_slice(&salt);
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend_from_slice(&ciphertext);
+
+    Ok(encrypted)
+}
+
+/// Decrypts what [`encrypt`] returned, failing with [`StegoError::WrongPassword`] if the tag
+/// doesn't match.
+pub(crate) fn decrypt(encrypted: &[u8], password: &str) -> Result<Vec<u8>, StegoError> {
+    if encrypted.len() < OVERHEAD {
+        return Err(StegoError::CorruptPayload(format!("encrypted payload of {} bytes is too short", encrypted.len())));
+    }
+
+    let (salt, rest) = encrypted.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let cipher = XChaCha20Poly1305::new(&derive_key(password, salt)?);
+
+    // the CRC in the header already caught damaged payloads, so a bad tag means a bad password
+    cipher.decrypt(XNonce::from_slice(nonce), ciphertext).map_err(|_| StegoError::WrongPassword)
+}
+
+/// Argon2id key derived from `password` and `salt`, at least 8 bytes long.
+pub(crate) fn derive_key(password: &str, salt: &[u8]) -> Result<Key, StegoError> {
+    if password.is_empty() {
+        return Err(StegoError::InvalidOption("the password can't be empty".to_string()));
+    }
+
+    let params = Params::new(ARGON2_MEMORY_KIB, ARGON2_ITERATIONS, ARGON2_PARALLELISM, Some(32))
+        .map_err(|err| StegoError::InvalidOption(err.to_string()))?;
+    let mut key = Key::default();
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|err| StegoError::InvalidOption(err.to_string()))?;
+
+    Ok(key)
+}
@@ -0,0 +1,29 @@
+use sha2::{Digest, Sha256};
+
+use crate::{PayloadFlags, StegoError};
+use crate::container::Header;
+use crate::crypto;
+
+/// Keeps the salt of the hidden slot apart from anything else derived from the decoy header.
+const DOMAIN: &[u8] = b"wasm-steganography hidden slot v1\0";
+
+/// Payload hidden behind a decoy, in a slot only its password finds.
+///
+/// The decoy takes the regular slot, the hidden one takes the carrier positions left over in an
+/// order drawn from the password. It has a header of its own, but at positions nobody without
+/// the password can tell from those left untouched.
+pub(crate) struct HiddenSlot<'a> {
+    pub(crate) payload: &'a [u8],
+    pub(crate) flags: PayloadFlags,
+    pub(crate) password: &'a str,
+}
+
+/// Seed of the order the hidden slot visits the carrier positions in, derived from `password`
+/// with Argon2id so guessing it costs as much as guessing the encryption key.
+///
+/// The salt comes from the `decoy` header, which every carrier holding a decoy has its own of.
+pub(crate) fn seed(password: &str, decoy: &Header) -> Result<[u8; 32], StegoError> {
+    let salt = Sha256::new().chain_update(DOMAIN).chain_update(decoy.to_bytes()).finalize();
+
+    Ok(crypto::derive_key(password, &salt[..16])?.into())
+}
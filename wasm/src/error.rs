@@ -0,0 +1,26 @@
+use image::ImageError;
+use zune_image::errors::{ImageErrors, ImgEncodeErrors};
+use zune_jpeg::errors::DecodeErrors;
+
+pub use crate::bindings::StegoError;
+
+impl From<ImageError> for StegoError {
+    fn from(err: ImageError) -> Self {
+        StegoError::UnsupportedFormat(err.to_string())
+    }
+}
+
+impl From<ImageErrors> for StegoError {
+    fn from(err: ImageErrors) -> Self {
+        match err {
+            ImageErrors::EncodeErrors(ImgEncodeErrors::SecretTooLarge(_)) => StegoError::CapacityExceeded,
+            _ => StegoError::UnsupportedFormat(err.to_string().trim_end().to_string()),
+        }
+    }
+}
+
+impl From<DecodeErrors> for StegoError {
+    fn from(err: DecodeErrors) -> Self {
+        StegoError::UnsupportedFormat(err.to_string())
+    }
+}
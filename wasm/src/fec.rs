@@ -0,0 +1,254 @@
+use crate::StegoError;
+use crate::gf256::{alpha, div, mul};
+
+/// Longest Reed–Solomon block over GF(256), data and parity together.
+const BLOCK_LEN: usize = 255;
+
+/// Most parity bytes a block can take, past it there would be more parity than data.
+pub(crate) const MAX_PARITY: u8 = 128;
+
+/// Copies of the parity count in front of the blocks, so a single damaged one is outvoted.
+const PARITY_COPIES: usize = 3;
+
+/// Value at `x` of a polynomial with its lowest degree coefficient first.
+fn eval(poly: &[u8], x: u8) -> u8 {
+    poly.iter().rev().fold(0, |acc, &coef| mul(acc, x) ^ coef)
+}
+
+/// Data bytes in a full block with `parity` parity bytes.
+fn data_len(parity: u8) -> usize {
+    BLOCK_LEN - usize::from(parity)
+}
+
+/// Bytes [`encode`] turns `len` bytes into.
+fn encoded_len(len: usize, parity: u8) -> usize {
+    PARITY_COPIES + len + len.div_ceil(data_len(parity)) * usize::from(parity)
+}
+
+/// Most bytes [`encode`] can fit in `capacity` bytes with `parity` parity bytes per block.
+pub(crate) fn data_capacity(capacity: u64, parity: u8) -> Result<u64, StegoError> {
+    check_parity(parity)?;
+
+    let available = capacity.saturating_sub(PARITY_COPIES as u64);
+    let full_blocks = available / BLOCK_LEN as u64;
+    let last_block = available % BLOCK_LEN as u64;
+
+    Ok(full_blocks * data_len(parity) as u64 + last_block.saturating_sub(u64::from(parity)))
+}
+
+/// Splits `payload` into Reed–Solomon blocks with `parity` parity bytes each, which correct up
+/// to `parity / 2` damaged bytes per block.
+///
+/// Layout of the result:
+///
+/// | bytes  | field                                                   |
+/// |--------|---------------------------------------------------------|
+/// | 0..3   | parity bytes per block, three times                     |
+/// | 3..    | blocks of up to 255 bytes, data first and parity after  |
+///
+/// Every block holds `255 - parity` data bytes but the last, which is shortened. The container
+/// header in front of it isn't covered, it's stored several times instead.
+pub(crate) fn encode(payload: &[u8], parity: u8) -> Result<Vec<u8>, StegoError> {
+    check_parity(parity)?;
+
+    let generator = generator(parity);
+    let mut encoded = Vec::with_capacity(encoded_len(payload.len(), parity));
+    encoded.extend_from_slice(&[parity; PARITY_COPIES]);
+
+    for data in payload.chunks(data_len(parity)) {
+        encoded.extend_from_slice(data);
+        encoded.extend(remainder(data, &generator));
+    }
+
+    Ok(encoded)
+}
+
+/// Corrects in place what [`encode`] returned and had damaged since, returning how many bytes
+/// it corrected.
+///
+/// Fails if a block has more damaged bytes than its parity can correct, mostly. Damage past
+/// that can go unnoticed, so the caller should check the result some other way.
+pub(crate) fn correct(encoded: &mut [u8]) -> Result<u32, StegoError> {
+    let len = encoded.len();
+    let (copies, blocks) = encoded.split_at_mut_checked(PARITY_COPIES)
+        .ok_or_else(|| StegoError::CorruptPayload(format!("error corrected payload of {len} bytes is too short")))?;
+    let (parity, mut corrected) = vote(copies)?;
+
+    check_parity(parity).map_err(|_| StegoError::CorruptPayload(format!("{parity} parity bytes per block is out of range")))?;
+
+    for (index, block) in blocks.chunks_mut(BLOCK_LEN).enumerate() {
+        if block.len() <= usize::from(parity) {
+            return Err(StegoError::CorruptPayload(format!("block {index} is shorter than its parity")));
+        }
+
+        corrected += correct_block(block, parity)
+            .ok_or_else(|| StegoError::CorruptPayload(format!("block {index} has more damaged bytes than its parity can correct")))?;
+    }
+
+    Ok(corrected)
+}
+
+/// Data bytes of what [`correct`] went through, without the parity.
+pub(crate) fn strip(encoded: &[u8]) -> Vec<u8> {
+    let parity = usize::from(encoded[0]);
+
+    encoded[PARITY_COPIES..].chunks(BLOCK_LEN).flat_map(|block| &block[..block.len() - parity]).copied().collect()
+}
+
+fn check_parity(parity: u8) -> Result<(), StegoError> {
+    if !(1..=MAX_PARITY).contains(&parity) {
+        return Err(StegoError::InvalidOption(format!("error correction takes between 1 and {MAX_PARITY} parity bytes per block, got {parity}")));
+    }
+
+    Ok(())
+}
+
+/// Settles the parity count on the value most copies agree on and rewrites the others with it.
+fn vote(copies: &mut [u8]) -> Result<(u8, u32), StegoError> {
+    let parity = copies.iter()
+        .find(|&&candidate| copies.iter().filter(|&&copy| copy == candidate).count() * 2 > copies.len())
+        .copied()
+        .ok_or_else(|| StegoError::CorruptPayload("the parity bytes per block are damaged".to_string()))?;
+    let mut corrected = 0;
+
+    for copy in copies.iter_mut().filter(|copy| **copy != parity) {
+        *copy = parity;
+        corrected += 1;
+    }
+
+    Ok((parity, corrected))
+}
+
+/// Product of (x - α^i) for every i below `parity`, highest degree coefficient first.
+fn generator(parity: u8) -> Vec<u8> {
+    (0..usize::from(parity)).fold(vec![1], |poly, i| {
+        let mut next = poly.clone();
+        next.push(0);
+
+        for (j, &coef) in poly.iter().enumerate() {
+            next[j + 1] ^= mul(coef, alpha(i));
+        }
+
+        next
+    })
+}
+
+/// Parity of `data`, the remainder of dividing data(x)·x^parity by the generator.
+fn remainder(data: &[u8], generator: &[u8]) -> Vec<u8> {
+    let parity = generator.len() - 1;
+    let mut remainder = vec![0u8; parity];
+
+    for &byte in data {
+        let factor = byte ^ remainder[0];
+        remainder.rotate_left(1);
+        remainder[parity - 1] = 0;
+
+        for (slot, &coef) in remainder.iter_mut().zip(&generator[1..]) {
+            *slot ^= mul(coef, factor);
+        }
+    }
+
+    remainder
+}
+
+/// Corrects a single block in place, `None` if it's past what its parity can correct.
+///
+/// The byte at index `j` of an `n` byte block is the coefficient of x^(n - 1 - j), so its error
+/// locator is α^(n - 1 - j).
+fn correct_block(block: &mut [u8], parity: u8) -> Option<u32> {
+    let n = block.len();
+    let syndromes = syndromes_of(block, parity);
+
+    if syndromes.iter().all(|&syndrome| syndrome == 0) {
+        return Some(0);
+    }
+
+    let locator = error_locator(&syndromes);
+    let errors = locator.len() - 1;
+
+    if errors * 2 > usize::from(parity) {
+        return None;
+    }
+
+    // Chien search for the roots of the locator, which are the inverses of the error locators
+    let positions: Vec<usize> = (0..n).filter(|&j| eval(&locator, alpha(BLOCK_LEN - (n - 1 - j))) == 0).collect();
+
+    if positions.len() != errors {
+        return None;
+    }
+
+    // Forney: e = X · Ω(X⁻¹) / Λ'(X⁻¹), with Ω = S·Λ mod x^parity
+    let mut evaluator = vec![0u8; usize::from(parity)];
+
+    for (i, &syndrome) in syndromes.iter().enumerate() {
+        for (j, &coef) in locator.iter().enumerate().take(usize::from(parity) - i) {
+            evaluator[i + j] ^= mul(syndrome, coef);
+        }
+    }
+
+    let derivative: Vec<u8> = locator.iter().enumerate().skip(1).map(|(i, &coef)| if i % 2 == 1 { coef } else { 0 }).collect();
+
+    for &j in &positions {
+        let locator_value = alpha(n - 1 - j);
+        let inverse = div(1, locator_value);
+        let denominator = eval(&derivative, inverse);
+
+        if denominator == 0 {
+            return None;
+        }
+
+        block[j] ^= mul(locator_value, div(eval(&evaluator, inverse), denominator));
+    }
+
+    // a block damaged past its parity can still look correctable, the syndromes tell
+    let clean = syndromes_of(block, parity).iter().all(|&syndrome| syndrome == 0);
+
+    clean.then_some(errors as u32)
+}
+
+/// Block polynomial evaluated at every root of the generator, all zero for an intact block.
+fn syndromes_of(block: &[u8], parity: u8) -> Vec<u8> {
+    // the block bytes are the coefficients, highest degree first
+    (0..usize::from(parity)).map(|i| block.iter().fold(0, |acc, &coef| mul(acc, alpha(i)) ^ coef)).collect()
+}
+
+/// Error locator polynomial found by Berlekamp–Massey, lowest degree coefficient first, padded
+/// to as many coefficients as errors plus one. A zero last coefficient means uncorrectable.
+fn error_locator(syndromes: &[u8]) -> Vec<u8> {
+    let mut locator = vec![1u8];
+    let mut previous = vec![1u8];
+    let mut errors = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1u8;
+
+    for (n, &syndrome) in syndromes.iter().enumerate() {
+        let discrepancy = (1..=errors).fold(syndrome, |acc, i| acc ^ mul(*locator.get(i).unwrap_or(&0), syndromes[n - i]));
+
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let factor = div(discrepancy, previous_discrepancy);
+        let mut next = locator.clone();
+        next.resize(next.len().max(previous.len() + shift), 0);
+
+        for (i, &coef) in previous.iter().enumerate() {
+            next[i + shift] ^= mul(factor, coef);
+        }
+
+        if 2 * errors <= n {
+            previous = std::mem::replace(&mut locator, next);
+            errors = n + 1 - errors;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = next;
+            shift += 1;
+        }
+    }
+
+    locator.resize(errors + 1, 0);
+
+    locator
+}
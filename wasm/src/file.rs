@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use crate::{FilePayload, StegoError};
+
+/// Longest file name stored, the most bytes common file systems allow in a name.
+const MAX_NAME_LEN: usize = 255;
+
+/// Longest media type stored, as its length takes a single byte.
+const MAX_MEDIA_TYPE_LEN: usize = u8::MAX as usize;
+
+/// Bytes a file takes besides its name, media type and content.
+pub(crate) const OVERHEAD: usize = 2 + 1 + 4;
+
+/// Puts the name, media type and size of `file` in front of its content:
+///
+/// | bytes        | field                      |
+/// |--------------|----------------------------|
+/// | 0..2         | name length `n`            |
+/// | 2..2 + n     | UTF-8 name                 |
+/// | 2 + n        | media type length `m`      |
+/// | 3 + n..      | media type, `m` bytes      |
+/// | then 4 bytes | content size               |
+/// | then         | content                    |
+///
+/// Lengths and the size are little endian.
+pub(crate) fn pack(file: &FilePayload) -> Result<Vec<u8>, StegoError> {
+    check(file)?;
+
+    let mut packed = Vec::with_capacity(OVERHEAD + file.name.len() + file.media_type.len() + file.content.len());
+    write_entry(file, &mut packed);
+    packed.extend_from_slice(&file.content);
+
+    Ok(packed)
+}
+
+/// Reads back what [`pack`] returned.
+pub(crate) fn unpack(packed: &[u8]) -> Result<FilePayload, StegoError> {
+    let mut rest = packed;
+    let (name, media_type, size) = read_entry(&mut rest)?;
+
+    if rest.len() != size {
+        return Err(StegoError::CorruptPayload(format!("the file should have {size} bytes but has {}", rest.len())));
+    }
+
+    Ok(FilePayload { name, media_type, content: rest.to_vec() })
+}
+
+/// Lays `files` out as a directory followed by their contents, so a bundle costs little more
+/// than its files:
+///
+/// | bytes   | field                                                    |
+/// |---------|----------------------------------------------------------|
+/// | 0..2    | entry count, little endian                               |
+/// | 2..     | name, media type and size of every entry, as in [`pack`] |
+/// | then    | contents of every entry, in the same order               |
+///
+/// Names must be unique so no entry overwrites another when extracted.
+pub(crate) fn pack_archive(files: &[FilePayload]) -> Result<Vec<u8>, StegoError> {
+    if files.is_empty() || files.len() > usize::from(u16::MAX) {
+        return Err(StegoError::InvalidOption(format!("an archive holds between 1 and {} files, got {}", u16::MAX, files.len())));
+    }
+
+    let mut names = HashSet::new();
+
+    for file in files {
+        check(file)?;
+
+        if !names.insert(file.name.as_str()) {
+            return Err(StegoError::InvalidOption(format!("{:?} is in the archive more than once", file.name)));
+        }
+    }
+
+    Ok(archive_bytes(files))
+}
+
+/// [`pack_archive`] without the checks, for files that went through them already.
+pub(crate) fn archive_bytes(files: &[FilePayload]) -> Vec<u8> {
+    let mut packed = (files.len() as u16).to_le_bytes().to_vec();
+
+    for file in files {
+        write_entry(file, &mut packed);
+    }
+
+    for file in files {
+        packed.extend_from_slice(&file.content);
+    }
+
+    packed
+}
+
+/// Reads back what [`pack_archive`] returned.
+pub(crate) fn unpack_archive(packed: &[u8]) -> Result<Vec<FilePayload>, StegoError> {
+    let (count, mut rest) = packed.split_first_chunk::<2>()
+        .ok_or_else(|| StegoError::CorruptPayload(format!("archive of {} bytes is too short", packed.len())))?;
+    let entries = (0..u16::from_le_bytes(*count)).map(|_| read_entry(&mut rest)).collect::<Result<Vec<_>, _>>()?;
+
+    // sizes are 32 bit, so is usize in wasm, their sum can overflow
+    let total = entries.iter().try_fold(0usize, |total, (_, _, size)| total.checked_add(*size));
+
+    if total != Some(rest.len()) {
+        return Err(StegoError::CorruptPayload(format!("the archive files don't add up to its {} bytes", rest.len())));
+    }
+
+    let mut names = HashSet::new();
+    let mut files = Vec::with_capacity(entries.len());
+
+    for (name, media_type, size) in entries {
+        if !names.insert(name.clone()) {
+            return Err(StegoError::CorruptPayload(format!("{name:?} is in the archive more than once")));
+        }
+
+        let (content, remaining) = rest.split_at(size);
+        rest = remaining;
+        files.push(FilePayload { name, media_type, content: content.to_vec() });
+    }
+
+    Ok(files)
+}
+
+/// Fails unless `file` has a bare name, a media type and fits in a payload.
+fn check(file: &FilePayload) -> Result<(), StegoError> {
+    check_name(&file.name)?;
+
+    if file.media_type.is_empty() || file.media_type.len() > MAX_MEDIA_TYPE_LEN || !file.media_type.contains('/') || !file.media_type.is_ascii() {
+        return Err(StegoError::InvalidOption(format!("{:?} isn't a media type like text/plain", file.media_type)));
+    }
+
+    if u32::try_from(file.content.len()).is_err() {
+        return Err(StegoError::CapacityExceeded);
+    }
+
+    Ok(())
+}
+
+/// Fails unless `name` is a bare file name, without any folder.
+fn check_name(name: &str) -> Result<(), StegoError> {
+    if name.is_empty() || name.len() > MAX_NAME_LEN || name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        return Err(StegoError::InvalidOption(format!("{name:?} isn't a file name without folders")));
+    }
+
+    Ok(())
+}
+
+/// Appends the name, media type and size of a checked `file`.
+fn write_entry(file: &FilePayload, packed: &mut Vec<u8>) {
+    packed.extend_from_slice(&(file.name.len() as u16).to_le_bytes());
+    packed.extend_from_slice(file.name.as_bytes());
+    packed.push(file.media_type.len() as u8);
+    packed.extend_from_slice(file.media_type.as_bytes());
+    packed.extend_from_slice(&(file.content.len() as u32).to_le_bytes());
+}
+
+/// Reads what [`write_entry`] appended off the front of `packed`.
+fn read_entry(packed: &mut &[u8]) -> Result<(String, String, usize), StegoError> {
+    let truncated = || StegoError::CorruptPayload("a file name, media type or size is cut short".to_string());
+
+    let (name_len, rest) = packed.split_first_chunk::<2>().ok_or_else(truncated)?;
+    let (name, rest) = rest.split_at_checked(u16::from_le_bytes(*name_len).into()).ok_or_else(truncated)?;
+    let (media_type_len, rest) = rest.split_first().ok_or_else(truncated)?;
+    let (media_type, rest) = rest.split_at_checked((*media_type_len).into()).ok_or_else(truncated)?;
+    let (size, rest) = rest.split_first_chunk::<4>().ok_or_else(truncated)?;
+    *packed = rest;
+
+    let name = String::from_utf8(name.to_vec()).map_err(|_| StegoError::CorruptPayload("the file name isn't valid UTF-8".to_string()))?;
+    let media_type = String::from_utf8(media_type.to_vec()).map_err(|_| StegoError::CorruptPayload("the media type isn't valid UTF-8".to_string()))?;
+
+    // hosts write the file under this name, it must not reach outside the folder they pick
+    check_name(&name).map_err(|_| StegoError::CorruptPayload(format!("{name:?} isn't a valid file name")))?;
+
+    Ok((name, media_type, u32::from_le_bytes(*size) as usize))
+}
@@ -0,0 +1,61 @@
+/// Non zero elements of the field, all of them powers of α.
+const ORDER: usize = 255;
+
+/// x^8 + x^4 + x^3 + x^2 + 1, the field polynomial used by most Reed–Solomon codes over GF(256).
+const PRIMITIVE: u16 = 0x11D;
+
+/// Powers of the generator α, doubled so products of two logarithms need no modulo.
+const EXP: [u8; 2 * ORDER] = exp_table();
+/// Logarithms in base α of every non zero element.
+const LOG: [u8; 256] = log_table();
+
+const fn exp_table() -> [u8; 2 * ORDER] {
+    let mut exp = [0u8; 2 * ORDER];
+    let mut x: u16 = 1;
+    let mut i = 0;
+
+    while i < 2 * ORDER {
+        exp[i] = x as u8;
+        x <<= 1;
+
+        if x & 0x100 != 0 {
+            x ^= PRIMITIVE;
+        }
+
+        i += 1;
+    }
+
+    exp
+}
+
+const fn log_table() -> [u8; 256] {
+    let mut log = [0u8; 256];
+    let mut i = 0;
+
+    while i < ORDER {
+        log[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    log
+}
+
+pub(crate) fn mul(a: u8, b: u8) -> u8 {
+    match (a, b) {
+        (0, _) | (_, 0) => 0,
+        _ => EXP[LOG[a as usize] as usize + LOG[b as usize] as usize],
+    }
+}
+
+/// `a / b`, `b` must not be zero.
+pub(crate) fn div(a: u8, b: u8) -> u8 {
+    match a {
+        0 => 0,
+        _ => EXP[LOG[a as usize] as usize + ORDER - LOG[b as usize] as usize],
+    }
+}
+
+/// α^`power`.
+pub(crate) fn alpha(power: usize) -> u8 {
+    EXP[power % ORDER]
+}
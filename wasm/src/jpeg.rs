@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use std::io::Cursor;
+use zune_image::codecs::jpeg::{JpegEncoder, JpegTranscoder, SamplingFactor};
+use zune_image::errors::{ImageErrors, ImgEncodeErrors};
+use zune_image::traits::StegoEncoder;
+use zune_jpeg::JpegDecoder;
+use zune_jpeg::zune_core::options::{DecoderOptions, EncoderOptions};
+
+use crate::{CarrierFormat, EmbedOptions, ExtractOptions, PayloadFlags, ProbeReport, StegoError};
+use crate::container::{self, Header};
+use crate::decoy::{self, HiddenSlot};
+use crate::scatter::{self, Scatter};
+
+/// Quality images are re-encoded at when `options` don't choose one.
+const DEFAULT_QUALITY: u8 = 80;
+
+/// Highest JPEG quality, the one that quantizes least.
+const MAX_QUALITY: u8 = 100;
+
+/// Hides `payload` in the image with J-Steg.
+///
+/// Baseline images are transcoded, they keep their quantization and Huffman tables and every
+/// byte but those the changed coefficients take. Anything else is decoded and re-encoded with the
+/// standard tables and the chroma subsampling of the image, as are baseline images whose own
+/// Huffman tables have no code for some changed coefficient and every image if `options` choose a
+/// quality. Progressive images stay progressive.
+///
+/// Every carrier block holds a byte. With a key in `options` the blocks used and the order of the
+/// bits inside each one come from the key, otherwise the bytes fill the first blocks in order.
+/// A `hidden` slot goes in the blocks `payload` left over.
+pub(crate) fn embed(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, hidden: Option<&HiddenSlot>, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+    let mut zune_jpeg_encoder = encoder(&image, options)?;
+    let transcoder = JpegTranscoder::new();
+
+    if let (None, Ok(carrier_bytes)) = (options.quality, transcoder.secret_capacity(&image)) {
+        let secret = scattered_secret(payload, flags, options, hidden, carrier_bytes)?;
+
+        match transcoder.transcode_with_scattered_secret(&image, &secret) {
+            // re-encoding codes the coefficients with complete tables
+            Err(ImageErrors::EncodeErrors(ImgEncodeErrors::MissingHuffmanCode(_))) => {}
+            transcoded => return Ok(transcoded?),
+        }
+    }
+
+    let loaded_img = zune_image::image::Image::read(image, DecoderOptions::default())?;
+    let (width, height) = loaded_img.dimensions();
+    let carrier_bytes = zune_jpeg_encoder.secret_capacity(width, height, loaded_img.colorspace())?;
+    let secret = scattered_secret(payload, flags, options, hidden, carrier_bytes)?;
+
+    let encoded_image = zune_jpeg_encoder.encode_with_scattered_secret(&loaded_img, &secret)?;
+
+    Ok(encoded_image.to_vec())
+}
+
+/// Byte of every one of the `carrier_bytes` carrier blocks [`embed`] writes, `None` for those it
+/// leaves alone.
+fn scattered_secret(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, hidden: Option<&HiddenSlot>, carrier_bytes: usize) -> Result<Vec<Option<u8>>, StegoError> {
+    let sealed = container::seal(payload, flags)?;
+
+    if sealed.len() > carrier_bytes {
+        return Err(StegoError::CapacityExceeded);
+    }
+
+    let mut secret = vec![None; carrier_bytes];
+
+    if let Some(hidden) = hidden {
+        let sealed_hidden = container::seal(hidden.payload, hidden.flags)?;
+
+        if sealed.len() + sealed_hidden.len() > carrier_bytes {
+            return Err(StegoError::CapacityExceeded);
+        }
+
+        let decoy = Header::new(payload, flags, 0)?;
+
+        for (byte, (block, bit_order)) in sealed_hidden.into_iter().zip(hidden_blocks(carrier_bytes, options.key.as_deref(), &decoy, hidden.password)?) {
+            secret[block] = Some(scatter::spread_bits(byte, &bit_order));
+        }
+    }
+
+    for (byte, (block, bit_order)) in sealed.into_iter().zip(carrier_blocks(Scatter::new(carrier_bytes as u64, options.key.as_deref()))) {
+        secret[block] = Some(scatter::spread_bits(byte, &bit_order));
+    }
+
+    Ok(secret)
+}
+
+/// How many payload bytes [`embed`] can hide in the image with `options`, worked out from the
+/// headers alone.
+pub(crate) fn capacity(image: &[u8], options: &EmbedOptions) -> Result<u64, StegoError> {
+    let zune_jpeg_encoder = encoder(image, options)?;
+
+    let carrier_bytes = match JpegTranscoder::new().secret_capacity(image) {
+        Ok(carrier_bytes) if options.quality.is_none() => carrier_bytes,
+        _ => {
+            let mut decoder = JpegDecoder::new(image);
+            decoder.decode_headers()?;
+            let (width, height) = decoder.dimensions().unwrap();
+            let colorspace = decoder.get_output_colorspace().unwrap();
+
+            zune_jpeg_encoder.secret_capacity(width, height, colorspace)?
+        }
+    };
+
+    Ok(carrier_bytes.saturating_sub(container::header_len(options.error_correction > 0)) as u64)
+}
+
+/// Encoder re-encoding the image at the quality `options` choose, with its chroma subsampled as
+/// in the image and progressive if the image is.
+fn encoder(image: &[u8], options: &EmbedOptions) -> Result<JpegEncoder, StegoError> {
+    let quality = match options.quality {
+        Some(quality @ 1..=MAX_QUALITY) => quality,
+        Some(quality) => return Err(StegoError::InvalidOption(format!("JPEG quality must be between 1 and {MAX_QUALITY}, got {quality}"))),
+        None => DEFAULT_QUALITY,
+    };
+
+    let mut decoder = JpegDecoder::new(image);
+    decoder.decode_headers()?;
+
+    let progressive = decoder.info().unwrap().sof.is_progressive();
+    let mut encoder = JpegEncoder::new_with_options(
+        EncoderOptions::default().set_quality(quality).set_jpeg_encode_progressive(progressive),
+    );
+    // decode_headers already went through the frame header
+    encoder.set_sampling_factor(sampling_factor(&decoder.sampling_factors().unwrap()));
+
+    Ok(encoder)
+}
+
+/// Sampling factor of luma against chroma closest to the sampling `factors` of every component of
+/// an image, among those every carrier block can be written in a single scan with.
+fn sampling_factor(factors: &[(usize, usize)]) -> SamplingFactor {
+    match factors {
+        [(luma_h, luma_v), (chroma_h, chroma_v), ..] => {
+            let horizontal = (luma_h / chroma_h).clamp(1, 2);
+            let vertical = (luma_v / chroma_v).clamp(1, 2);
+
+            SamplingFactor::from_factors(horizontal as u8, vertical as u8).unwrap()
+        }
+        _ => SamplingFactor::F_1_1,
+    }
+}
+
+/// Reads back the header and the payload hidden by [`embed`] with the same key.
+pub(crate) fn extract(image: &[u8], options: &ExtractOptions) -> Result<(Header, Vec<u8>), StegoError> {
+    let decoded_img = image::codecs::jpeg::JpegDecoder::new(Cursor::new(image))?;
+    let hidden_bytes = decoded_img.get_secret()?;
+    let hidden = carrier_blocks(Scatter::new(hidden_bytes.len() as u64, options.key.as_deref()))
+        .map(|(block, bit_order)| scatter::gather_bits(hidden_bytes[block], &bit_order));
+
+    container::unseal(hidden)
+}
+
+/// Reads back the slot [`embed`] hid behind a decoy, if `password` is its own.
+pub(crate) fn extract_hidden(image: &[u8], options: &ExtractOptions, password: &str) -> Result<(Header, Vec<u8>), StegoError> {
+    let decoded_img = image::codecs::jpeg::JpegDecoder::new(Cursor::new(image))?;
+    let hidden_bytes = decoded_img.get_secret()?;
+    let decoy = container::read_header(&mut carrier_blocks(Scatter::new(hidden_bytes.len() as u64, options.key.as_deref()))
+        .map(|(block, bit_order)| scatter::gather_bits(hidden_bytes[block], &bit_order)))?;
+    let hidden = hidden_blocks(hidden_bytes.len(), options.key.as_deref(), &decoy, password)?
+        .map(|(block, bit_order)| scatter::gather_bits(hidden_bytes[block], &bit_order));
+
+    container::unseal(hidden)
+}
+
+/// Describes the image and the header of the payload it hides, if any, without decoding the pixels.
+pub(crate) fn probe(image: &[u8]) -> Result<ProbeReport, StegoError> {
+    let mut decoder = JpegDecoder::new(image);
+    // enough for every copy of the header, if it's stored several times
+    let hidden_bytes = decoder.read_secret(container::header_len(true))?;
+    // read_secret already went through the headers
+    let (width, height) = decoder.dimensions().unwrap();
+
+    Ok(ProbeReport {
+        format: CarrierFormat::Jpeg,
+        width: width as u32,
+        height: height as u32,
+        capacity: capacity(image, &EmbedOptions::default())?,
+        header: container::peek(hidden_bytes.into_iter()).map(Into::into),
+    })
+}
+
+/// Carrier block of every hidden byte, in the order `scatter` visits them, with the order of its
+/// bits.
+fn carrier_blocks(mut scatter: Scatter) -> impl Iterator<Item = (usize, [u8; 8])> {
+    std::iter::from_fn(move || {
+        let block = scatter.next()?;
+
+        Some((block as usize, scatter.bit_order()))
+    })
+}
+
+/// Carrier blocks the `decoy` payload found with `key` leaves over, in the order drawn from the
+/// `password` of the slot hidden behind it.
+fn hidden_blocks(blocks: usize, key: Option<&str>, decoy: &Header, password: &str) -> Result<impl Iterator<Item = (usize, [u8; 8])> + use<>, StegoError> {
+    let taken: HashSet<usize> = carrier_blocks(Scatter::new(blocks as u64, key))
+        .take(decoy.stored_len() + decoy.length as usize)
+        .map(|(block, _)| block)
+        .collect();
+    let scatter = Scatter::seeded(blocks as u64, decoy::seed(password, decoy)?);
+
+    Ok(carrier_blocks(scatter).filter(move |(block, _)| !taken.contains(block)))
+}
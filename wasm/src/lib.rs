@@ -1,17 +1,34 @@
 mod bindings;
+mod bmp;
+mod compression;
+mod container;
+mod crypto;
+mod decoy;
+mod error;
+mod fec;
+mod file;
+mod gf256;
+mod jpeg;
+mod lsb;
+mod png;
+mod recipients;
+mod scatter;
+mod shamir;
+mod signing;
+mod split;
 
 extern crate alloc;
 
-use std::io::Cursor;
-use image::{ImageReader, Rgb};
-use zune_image::{traits::StegoEncoder};
-pub use bindings::Guest;
-use zune_jpeg::zune_core::options::DecoderOptions;
+pub use bindings::{CarrierFormat, Decoy, DecodedBytes, DecodedPayload, DecodedSecret, EmbedOptions, ExtractOptions, FilePayload, Guest, HiddenContent, Keypair, PayloadFlags, PayloadHeader, ProbeReport, SignatureStatus, SigningKeypair};
+pub use error::StegoError;
+
+use container::Header;
+use decoy::HiddenSlot;
 
 bindings::export!(Steganography with_types_in bindings);
 
 fn set_panic_hook() {
-    // During integration tests, the wasm import log function is not 
+    // During integration tests, the wasm import log function is not
     // satisfied and breaks during execution. We don't need it during tests
     // anyway, so it's a noop if that's the case.
     if std::env::var("RUNNING_TESTS").is_err() {
@@ -21,139 +38,514 @@ fn set_panic_hook() {
     }
 }
 
+impl Default for EmbedOptions {
+    fn default() -> Self {
+        EmbedOptions { bits_per_channel: 1, quality: None, key: None, password: None, recipients: Vec::new(), signing_key: None, error_correction: 0, decoy: None }
+    }
+}
+
+impl HiddenContent {
+    /// Raw bytes of the content, the content alone for a file and the archive as embedded for
+    /// several.
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            HiddenContent::Text(text) => text.into_bytes(),
+            HiddenContent::Binary(payload) => payload,
+            HiddenContent::File(file) => file.content,
+            HiddenContent::Archive(files) => file::archive_bytes(&files),
+        }
+    }
+
+    /// Payload to embed for the content, with the flags that tell how to read it back.
+    fn into_payload(self) -> Result<(Vec<u8>, PayloadFlags), StegoError> {
+        Ok(match self {
+            HiddenContent::Text(secret) => (secret.into_bytes(), PayloadFlags::empty()),
+            HiddenContent::Binary(payload) => (payload, PayloadFlags::BINARY),
+            HiddenContent::File(file) => (file::pack(&file)?, PayloadFlags::FILE),
+            HiddenContent::Archive(files) => (file::pack_archive(&files)?, PayloadFlags::ARCHIVE),
+        })
+    }
+}
+
+/// Carrier format of `image`, told from its content.
+fn carrier_format(image: &[u8]) -> Result<CarrierFormat, StegoError> {
+    match image::guess_format(image) {
+        Ok(image::ImageFormat::Bmp) => Ok(CarrierFormat::Bmp),
+        Ok(image::ImageFormat::Jpeg) => Ok(CarrierFormat::Jpeg),
+        Ok(image::ImageFormat::Png) => Ok(CarrierFormat::Png),
+        _ => Err(StegoError::UnsupportedFormat("only BMP, JPEG and PNG images are supported".to_string())),
+    }
+}
+
+/// Turns `payload` into what a carrier hides, along with the flags telling how: compressed when
+/// that makes it smaller, then encrypted if `options` has a password or recipients and signed if
+/// it has a signing key. [`protect`] adds the error correction on top.
+fn seal(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions) -> Result<(Vec<u8>, PayloadFlags), StegoError> {
+    // settled up front so the signature covers it
+    let flags = match options.error_correction {
+        0 => flags,
+        _ => flags | PayloadFlags::ERROR_CORRECTED,
+    };
+    let (payload, flags) = match compression::compress(payload) {
+        Some(compressed) => (compressed, flags | PayloadFlags::COMPRESSED),
+        None => (payload.to_vec(), flags),
+    };
+    let (payload, flags) = match (&options.password, options.recipients.as_slice()) {
+        (Some(_), [_, ..]) => return Err(StegoError::InvalidOption("a secret is encrypted with a password or to recipients, not both".to_string())),
+        (Some(password), []) => (crypto::encrypt(&payload, password)?, flags | PayloadFlags::ENCRYPTED),
+        (None, []) => (payload, flags),
+        (None, recipients) => (recipients::encrypt(&payload, recipients)?, flags | PayloadFlags::RECIPIENTS),
+    };
+
+    match &options.signing_key {
+        Some(signing_key) => Ok((signing::sign(&payload, flags | PayloadFlags::SIGNED, signing_key)?, flags | PayloadFlags::SIGNED)),
+        None => Ok((payload, flags)),
+    }
+}
+
+/// Splits what a single carrier hides in error correcting blocks if `options` asks for them.
+fn protect(payload: Vec<u8>, options: &EmbedOptions) -> Result<Vec<u8>, StegoError> {
+    match options.error_correction {
+        0 => Ok(payload),
+        parity => fec::encode(&payload, parity),
+    }
+}
+
+/// Hides `payload`, sealed as `options` ask, in a carrier of any supported format, behind the
+/// decoy they ask for if any.
+fn embed(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+    let (payload, flags) = seal(payload, flags, options)?;
+    let payload = protect(payload, options)?;
+
+    let Some(decoy) = &options.decoy else {
+        return hide(&payload, flags, options, None, image);
+    };
+
+    let password = match &options.password {
+        Some(password) if *password == decoy.password => return Err(StegoError::InvalidOption("the decoy needs a password of its own".to_string())),
+        Some(password) => password,
+        None => return Err(StegoError::InvalidOption("only a secret encrypted with a password can hide behind a decoy".to_string())),
+    };
+
+    // the decoy goes where any payload would, sealed like the secret but with its own password
+    let decoy_options = EmbedOptions { password: Some(decoy.password.clone()), decoy: None, ..options.clone() };
+    let (decoy_payload, decoy_flags) = seal(decoy.message.as_bytes(), PayloadFlags::empty(), &decoy_options)?;
+    let hidden = HiddenSlot { payload: &payload, flags, password };
+
+    hide(&protect(decoy_payload, options)?, decoy_flags, options, Some(&hidden), image)
+}
+
+/// Hides `payload`, sealed as `options` ask, split across every image in `images`.
+fn embed_split(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, images: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, StegoError> {
+    check_no_decoy(options)?;
+
+    let (payload, flags) = seal(payload, flags | PayloadFlags::SPLIT, options)?;
+    let capacities = images.iter()
+        .map(|image| Ok(carrier_capacity(image.clone(), options)?.saturating_sub(split::CHUNK_HEADER_LEN as u64)))
+        .collect::<Result<Vec<_>, StegoError>>()?;
+
+    // every chunk gets its own error correction, so each image can be repaired on its own
+    images.into_iter().zip(split::split(&payload, &capacities)?)
+        .map(|(image, chunk)| hide(&protect(chunk, options)?, flags, options, None, image))
+        .collect()
+}
+
+/// Seals `payload` once and hides a share of it in every one of `images`, any `threshold` of
+/// which recover it.
+fn embed_shared(payload: &[u8], flags: PayloadFlags, threshold: u8, options: &EmbedOptions, images: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, StegoError> {
+    check_no_decoy(options)?;
+
+    let (payload, flags) = seal(payload, flags | PayloadFlags::SHARED, options)?;
+    let shares = shamir::share(&payload, threshold, images.len())?;
+
+    images.into_iter().zip(shares)
+        .map(|(image, share)| hide(&protect(share, options)?, flags, options, None, image))
+        .collect()
+}
+
+/// Fails if `options` ask for a decoy, which only single images can hold.
+fn check_no_decoy(options: &EmbedOptions) -> Result<(), StegoError> {
+    match options.decoy {
+        Some(_) => Err(StegoError::InvalidOption("a decoy can't be hidden along a split or shared payload".to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Hides `payload` as it is in a carrier of any supported format, with a `hidden` slot behind it
+/// if any.
+fn hide(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, hidden: Option<&HiddenSlot>, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+    match carrier_format(&image)? {
+        CarrierFormat::Bmp => bmp::embed(payload, flags, options, hidden, &image),
+        CarrierFormat::Jpeg => jpeg::embed(payload, flags, options, hidden, image),
+        CarrierFormat::Png => png::embed(payload, flags, options, hidden, &image),
+    }
+}
+
+/// Payload bytes a carrier of any supported format can hide once error corrected as `options` ask.
+fn carrier_capacity(image: Vec<u8>, options: &EmbedOptions) -> Result<u64, StegoError> {
+    let capacity = match carrier_format(&image)? {
+        CarrierFormat::Bmp => bmp::capacity(&image, options),
+        CarrierFormat::Jpeg => jpeg::capacity(&image, options),
+        CarrierFormat::Png => png::capacity(&image, options),
+    }?;
+
+    match options.error_correction {
+        0 => Ok(capacity),
+        parity => fec::data_capacity(capacity, parity),
+    }
+}
+
+fn extract(image: Vec<u8>, options: &ExtractOptions) -> Result<(Header, Vec<u8>), StegoError> {
+    match carrier_format(&image)? {
+        CarrierFormat::Bmp => bmp::extract(&image, options),
+        CarrierFormat::Jpeg => jpeg::extract(&image, options),
+        CarrierFormat::Png => png::extract(&image, options),
+    }
+}
+
+/// Reads the slot a carrier of any supported format hides behind a decoy, if `password` is its own.
+fn extract_hidden(image: Vec<u8>, options: &ExtractOptions, password: &str) -> Result<(Header, Vec<u8>), StegoError> {
+    match carrier_format(&image)? {
+        CarrierFormat::Bmp => bmp::extract_hidden(&image, options, password),
+        CarrierFormat::Jpeg => jpeg::extract_hidden(&image, options, password),
+        CarrierFormat::Png => png::extract_hidden(&image, options, password),
+    }
+}
+
+/// Turns what `image` hides back into what was embedded, or what it hides behind a decoy when the
+/// password given only fails to open the decoy.
+fn decode_image(image: Vec<u8>, options: &ExtractOptions) -> Result<DecodedPayload, StegoError> {
+    match decode(extract(image.clone(), options)?, options) {
+        Err(StegoError::WrongPassword) => {
+            // only a password was wrong, and every wrong one is tried here, so trying tells nothing
+            let password = options.password.as_deref().ok_or(StegoError::WrongPassword)?;
+
+            extract_hidden(image, options, password)
+                .and_then(|extracted| decode(extracted, options))
+                .map_err(|_| StegoError::WrongPassword)
+        },
+        decoded => decoded,
+    }
+}
+
+/// Turns what a single carrier hid back into what was embedded.
+fn decode(extracted: (Header, Vec<u8>), options: &ExtractOptions) -> Result<DecodedPayload, StegoError> {
+    let (flags, payload, corrected_errors) = recover(extracted)?;
+    // a lone chunk or share tells what else it takes
+    let payload = if flags.contains(PayloadFlags::SPLIT) {
+        split::join(vec![payload])?
+    } else if flags.contains(PayloadFlags::SHARED) {
+        shamir::combine(vec![payload])?
+    } else {
+        payload
+    };
+    let (content, signature) = open(payload, flags, options)?;
+
+    Ok(DecodedPayload { content, signature, corrected_errors })
+}
+
+/// Turns what [`embed_split`] or [`embed_shared`] hid across `images`, in any order, back into what
+/// was embedded.
+fn decode_split(images: Vec<Vec<u8>>, options: &ExtractOptions) -> Result<DecodedPayload, StegoError> {
+    let mut set_flags = None;
+    let mut chunks = Vec::with_capacity(images.len());
+    let mut corrected_errors = 0;
+
+    for (index, image) in images.into_iter().enumerate() {
+        let (flags, chunk, corrected) = recover(extract(image, options)?)?;
+
+        if !flags.intersects(PayloadFlags::SPLIT | PayloadFlags::SHARED) {
+            return Err(StegoError::IncompleteSplit(format!("image {} doesn't hold a part of a split or shared payload", index + 1)));
+        }
+
+        if *set_flags.get_or_insert(flags) != flags {
+            return Err(StegoError::IncompleteSplit("the images hold parts of different payloads".to_string()));
+        }
+
+        chunks.push(chunk);
+        corrected_errors += corrected;
+    }
+
+    let flags = set_flags.ok_or_else(|| StegoError::InvalidOption("no images to join".to_string()))?;
+    let payload = if flags.contains(PayloadFlags::SHARED) {
+        shamir::combine(chunks)?
+    } else {
+        split::join(chunks)?
+    };
+    let (content, signature) = open(payload, flags, options)?;
+
+    Ok(DecodedPayload { content, signature, corrected_errors })
+}
+
+/// Undoes [`protect`] on what a carrier hid, returning its flags, the payload as sealed and the
+/// errors corrected.
+fn recover((header, mut payload): (Header, Vec<u8>)) -> Result<(PayloadFlags, Vec<u8>, u32), StegoError> {
+    if !header.flags.contains(PayloadFlags::ERROR_CORRECTED) {
+        return Ok((header.flags, payload, 0));
+    }
+
+    let corrected_errors = fec::correct(&mut payload)?;
+
+    // the checksum covers the blocks as embedded, it catches damage they couldn't correct. Blocks
+    // that needed no correction are intact, so a mismatch can only be damage to the checksum
+    if corrected_errors > 0 {
+        container::check_crc(&header, &payload)?;
+    }
+
+    Ok((header.flags, fec::strip(&payload), corrected_errors))
+}
+
+/// Turns a sealed payload back into what was embedded, undoing [`seal`] as its flags tell, along
+/// with the status of its signature.
+fn open(payload: Vec<u8>, flags: PayloadFlags, options: &ExtractOptions) -> Result<(HiddenContent, SignatureStatus), StegoError> {
+    let (payload, signature) = if flags.contains(PayloadFlags::SIGNED) {
+        signing::verify(&payload, flags)?
+    } else {
+        (payload, SignatureStatus::Unsigned)
+    };
+
+    let payload = if flags.contains(PayloadFlags::ENCRYPTED) {
+        let password = options.password.as_deref().ok_or(StegoError::PasswordRequired)?;
+
+        crypto::decrypt(&payload, password)?
+    } else if flags.contains(PayloadFlags::RECIPIENTS) {
+        recipients::decrypt(&payload, &options.identities)?
+    } else {
+        payload
+    };
+
+    let payload = if flags.contains(PayloadFlags::COMPRESSED) {
+        compression::decompress(&payload)?
+    } else {
+        payload
+    };
+
+    let content = if flags.contains(PayloadFlags::FILE) {
+        HiddenContent::File(file::unpack(&payload)?)
+    } else if flags.contains(PayloadFlags::ARCHIVE) {
+        HiddenContent::Archive(file::unpack_archive(&payload)?)
+    } else if flags.contains(PayloadFlags::BINARY) {
+        HiddenContent::Binary(payload)
+    } else {
+        // payloads from before the binary flag hold text, unless they aren't UTF-8
+        match String::from_utf8(payload) {
+            Ok(text) => HiddenContent::Text(text),
+            Err(err) => HiddenContent::Binary(err.into_bytes()),
+        }
+    };
+
+    Ok((content, signature))
+}
+
+/// Bytes the encryption and signature asked for in `options` add to a payload.
+fn overhead(options: &EmbedOptions) -> usize {
+    let encryption = match (&options.password, options.recipients.len()) {
+        (Some(_), _) => crypto::OVERHEAD,
+        (None, 0) => 0,
+        (None, recipients) => recipients::overhead(recipients),
+    };
+
+    match options.signing_key {
+        Some(_) => encryption + signing::OVERHEAD,
+        None => encryption,
+    }
+}
+
 pub struct Steganography;
 
 impl Guest for Steganography {
-    fn encode_secret_into_jpeg(secret: String, image: Vec<u8>) -> Vec<u8> {
+    fn encode_secret_into_jpeg(secret: String, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
+
+        let (payload, flags) = seal(secret.as_bytes(), PayloadFlags::empty(), &EmbedOptions::default())?;
+
+        jpeg::embed(&payload, flags, &EmbedOptions::default(), None, image)
+    }
+
+    fn decode_secret_from_jpeg(image: Vec<u8>) -> Result<String, StegoError> {
+        String::from_utf8(Self::decode_bytes_from_jpeg(image)?).map_err(|_| StegoError::InvalidUtf8)
+    }
+
+    fn encode_bytes_into_jpeg(payload: Vec<u8>, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
         set_panic_hook();
 
-        let mut zune_jpeg_encoder = zune_image::codecs::jpeg::JpegEncoder::new();
-        let loaded_img = zune_image::image::Image::read(image, DecoderOptions::default()).expect("failed to load image");
-        let encoded_image = zune_jpeg_encoder.encode_with_secret(
-            &loaded_img,
-            secret.as_bytes()
-        ).expect("failed to encode image as jpg with zune");
+        let (payload, flags) = seal(&payload, PayloadFlags::BINARY, &EmbedOptions::default())?;
 
-        encoded_image.to_vec()
+        jpeg::embed(&payload, flags, &EmbedOptions::default(), None, image)
     }
 
-    fn decode_secret_from_jpeg(image: Vec<u8>) -> String {
+    fn decode_bytes_from_jpeg(image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
         set_panic_hook();
 
-        let decoded_img = image::codecs::jpeg::JpegDecoder::new(Cursor::new(&image)).expect("failed to decode the jpg file");
+        let options = ExtractOptions { key: None, password: None, identities: Vec::new() };
 
-        decoded_img.get_secret().expect("error extracting the secret")
+        Ok(decode(jpeg::extract(&image, &options)?, &options)?.content.into_bytes())
     }
 
-    fn encode_secret_into_bmp(secret: String, image: Vec<u8>) -> Vec<u8> {
+    fn capacity_jpeg(image: Vec<u8>) -> Result<u64, StegoError> {
         set_panic_hook();
 
-        let cloned_image = image.to_vec();
-        let image = ImageReader::new(Cursor::new(&cloned_image)).with_guessed_format().unwrap().decode().unwrap();
-        let image::DynamicImage::ImageRgb8(mut img_buf) = image else {
-            panic!("image format not supported")
-        };
+        jpeg::capacity(&image, &EmbedOptions::default())
+    }
 
-        u64::checked_mul(secret.len() as u64, u8::BITS as u64).expect("the string is too long");
+    fn encode_secret_into_bmp(secret: String, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-        let mut secret_len = secret.len() as u64; // shadowing to make it mutable but keep semantics
-        let mut pixel_iter = img_buf.enumerate_pixels_mut();
+        let (payload, flags) = seal(secret.as_bytes(), PayloadFlags::empty(), &EmbedOptions::default())?;
 
-        for _ in (0..u64::BITS+2).step_by(3) {
-            secret_len <<= 3;
+        bmp::embed(&payload, flags, &EmbedOptions::default(), None, &image)
+    }
 
-            let mut pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            encode_bits_in_rgb_byte(&mut pixel.2, (secret_len >> 56) as u8);
-        }
+    fn decode_secret_from_bmp(image: Vec <u8>) -> Result<String, StegoError> {
+        String::from_utf8(Self::decode_bytes_from_bmp(image)?).map_err(|_| StegoError::InvalidUtf8)
+    }
 
-        for character in secret.as_bytes() {
-            let mut pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            encode_bits_in_rgb_byte(&mut pixel.2, *character);
+    fn encode_bytes_into_bmp(payload: Vec<u8>, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-            let mut pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            encode_bits_in_rgb_byte(&mut pixel.2, *character << 3);
+        let (payload, flags) = seal(&payload, PayloadFlags::BINARY, &EmbedOptions::default())?;
 
-            let mut pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            encode_bits_in_rgb_byte(&mut pixel.2, *character << 6);
-        }
+        bmp::embed(&payload, flags, &EmbedOptions::default(), None, &image)
+    }
+
+    fn decode_bytes_from_bmp(image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-        let mut ret = Vec::<u8>::new();
-        let mut writer = Cursor::new(&mut ret);
-        img_buf.write_to(&mut writer, image::ImageFormat::Bmp).expect("failed to write the image to the writer");
+        let options = ExtractOptions { key: None, password: None, identities: Vec::new() };
 
-        ret
+        Ok(decode(bmp::extract(&image, &options)?, &options)?.content.into_bytes())
     }
 
-    fn decode_secret_from_bmp(image: Vec <u8>) -> String {
+    fn capacity_bmp(image: Vec<u8>) -> Result<u64, StegoError> {
         set_panic_hook();
 
-        let image = ImageReader::new(Cursor::new(&image)).with_guessed_format().unwrap().decode().unwrap();
-        let image::DynamicImage::ImageRgb8(img_buf) = image else {
-            panic!("Image format not supported. Only BMP with 24bits of depth at the moment.")
-        };
+        bmp::capacity(&image, &EmbedOptions::default())
+    }
 
-        let mut pixel_iter = img_buf.enumerate_pixels();
-        let mut secret_len = 0u64;
+    fn encode_secret_into_png(secret: String, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-        for _ in (0..u64::BITS+2).step_by(3) {
-            let pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            secret_len = secret_len << 3 | decode_bits_from_rgb_byte(pixel.2);
-        }
+        let (payload, flags) = seal(secret.as_bytes(), PayloadFlags::empty(), &EmbedOptions::default())?;
+
+        png::embed(&payload, flags, &EmbedOptions::default(), None, &image)
+    }
 
-        secret_len >>= 5; // accounting for the 2 useless bits from the last pixel + 3 bits (???)
+    fn decode_secret_from_png(image: Vec<u8>) -> Result<String, StegoError> {
+        String::from_utf8(Self::decode_bytes_from_png(image)?).map_err(|_| StegoError::InvalidUtf8)
+    }
 
-        let mut secret: Vec<u8> = Vec::new(); 
+    fn encode_bytes_into_png(payload: Vec<u8>, image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-        for _ in 0..secret_len {
-            let mut character: u8;
-            let pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            character = decode_bits_from_rgb_byte(pixel.2) as u8;
+        let (payload, flags) = seal(&payload, PayloadFlags::BINARY, &EmbedOptions::default())?;
 
-            let pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            character = character << 3 | decode_bits_from_rgb_byte(pixel.2) as u8;
+        png::embed(&payload, flags, &EmbedOptions::default(), None, &image)
+    }
 
-            let pixel = pixel_iter.next().expect("exhausted pixel iterator before encoding all secret characters");
-            character = character << 2 | (decode_bits_from_rgb_byte(pixel.2) as u8 >> 1);
-            //                       ^ this one has to account for the "lost" bit when encoding a
-            //                       byte in 3 pixels (9 bits, last one always 0)
+    fn decode_bytes_from_png(image: Vec<u8>) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-            secret.push(character);
-        }
+        let options = ExtractOptions { key: None, password: None, identities: Vec::new() };
 
-        let secret_str = str::from_utf8(&secret).expect("secret was not valid utf8");
+        Ok(decode(png::extract(&image, &options)?, &options)?.content.into_bytes())
+    }
+
+    fn capacity_png(image: Vec<u8>) -> Result<u64, StegoError> {
+        set_panic_hook();
 
-        secret_str.to_string()
+        png::capacity(&image, &EmbedOptions::default())
     }
-}
 
-fn encode_bits_in_rgb_byte(rgb_pixel: &mut Rgb<u8>, message_byte: u8) {
-    // set original lsb to 0
-    rgb_pixel.0[0] &= 0xFE;
-    rgb_pixel.0[1] &= 0xFE;
-    rgb_pixel.0[2] &= 0xFE;
+    fn encode_secret_with_options(secret: String, image: Vec<u8>, options: EmbedOptions) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
+
+        embed(secret.as_bytes(), PayloadFlags::empty(), &options, image)
+    }
 
-    // get the new desired lsb
-    let lsb_red     = message_byte >> 7;
-    let lsb_green   = message_byte >> 6 & 0x1;
-    let lsb_blue    = message_byte >> 5 & 0x1;
+    fn encode_bytes_with_options(payload: Vec<u8>, image: Vec<u8>, options: EmbedOptions) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
 
-    // set it
-    rgb_pixel.0[0] |= lsb_red;
-    rgb_pixel.0[1] |= lsb_green;
-    rgb_pixel.0[2] |= lsb_blue;
-}
+        embed(&payload, PayloadFlags::BINARY, &options, image)
+    }
+
+    fn encode_file_with_options(file: FilePayload, image: Vec<u8>, options: EmbedOptions) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
+
+        embed(&file::pack(&file)?, PayloadFlags::FILE, &options, image)
+    }
+
+    fn encode_archive_with_options(files: Vec<FilePayload>, image: Vec<u8>, options: EmbedOptions) -> Result<Vec<u8>, StegoError> {
+        set_panic_hook();
+
+        embed(&file::pack_archive(&files)?, PayloadFlags::ARCHIVE, &options, image)
+    }
+
+    fn encode_split_with_options(content: HiddenContent, images: Vec<Vec<u8>>, options: EmbedOptions) -> Result<Vec<Vec<u8>>, StegoError> {
+        set_panic_hook();
+
+        let (payload, flags) = content.into_payload()?;
+
+        embed_split(&payload, flags, &options, images)
+    }
+
+    fn encode_shared_with_options(content: HiddenContent, images: Vec<Vec<u8>>, threshold: u8, options: EmbedOptions) -> Result<Vec<Vec<u8>>, StegoError> {
+        set_panic_hook();
+
+        let (payload, flags) = content.into_payload()?;
+
+        embed_shared(&payload, flags, threshold, &options, images)
+    }
+
+    fn capacity_with_options(image: Vec<u8>, options: EmbedOptions) -> Result<u64, StegoError> {
+        set_panic_hook();
+
+        Ok(carrier_capacity(image, &options)?.saturating_sub(overhead(&options) as u64))
+    }
+
+    fn decode_secret_with_options(image: Vec<u8>, options: ExtractOptions) -> Result<DecodedSecret, StegoError> {
+        let DecodedBytes { payload, signature, corrected_errors } = Self::decode_bytes_with_options(image, options)?;
+        let secret = String::from_utf8(payload).map_err(|_| StegoError::InvalidUtf8)?;
+
+        Ok(DecodedSecret { secret, signature, corrected_errors })
+    }
 
-fn decode_bits_from_rgb_byte(rgb_byte: &Rgb<u8>) -> u64 {
+    fn decode_bytes_with_options(image: Vec<u8>, options: ExtractOptions) -> Result<DecodedBytes, StegoError> {
+        let DecodedPayload { content, signature, corrected_errors } = Self::decode_with_options(image, options)?;
 
-    let bit_0 = rgb_byte.0[0] & 1;
-    let bit_1 = rgb_byte.0[1] & 1;
-    let bit_2 = rgb_byte.0[2] & 1;
+        Ok(DecodedBytes { payload: content.into_bytes(), signature, corrected_errors })
+    }
+
+    fn decode_with_options(image: Vec<u8>, options: ExtractOptions) -> Result<DecodedPayload, StegoError> {
+        set_panic_hook();
+
+        decode_image(image, &options)
+    }
+
+    fn decode_split_with_options(images: Vec<Vec<u8>>, options: ExtractOptions) -> Result<DecodedPayload, StegoError> {
+        set_panic_hook();
+
+        decode_split(images, &options)
+    }
+
+    fn generate_keypair() -> Keypair {
+        set_panic_hook();
+
+        recipients::generate_keypair()
+    }
+
+    fn generate_signing_keypair() -> SigningKeypair {
+        set_panic_hook();
 
-    let mut decoded_bits = bit_0 as u64;
-    decoded_bits <<= 1;
-    decoded_bits |= bit_1 as u64;
-    decoded_bits <<= 1;
-    decoded_bits |= bit_2 as u64;
+        signing::generate_keypair()
+    }
+
+    fn probe(image: Vec<u8>) -> Result<ProbeReport, StegoError> {
+        set_panic_hook();
 
-    decoded_bits
+        match carrier_format(&image)? {
+            CarrierFormat::Bmp => bmp::probe(&image),
+            CarrierFormat::Jpeg => jpeg::probe(&image),
+            CarrierFormat::Png => png::probe(&image),
+        }
+    }
 }
@@ -0,0 +1,274 @@
+use std::collections::HashSet;
+
+use image::DynamicImage;
+
+use crate::{CarrierFormat, PayloadFlags, ProbeReport, StegoError};
+use crate::container::{self, Header};
+use crate::decoy::{self, HiddenSlot};
+use crate::scatter::Scatter;
+
+/// Carrier samples taken by every byte in the sparse layout: its 8 bits, most significant first,
+/// and a padding bit that is always zero. For RGB images that's 3 pixels per byte.
+///
+/// The header always uses this layout so it can be found before knowing the bits per channel,
+/// and so do version 1 payloads.
+const SPARSE_SAMPLES_PER_BYTE: usize = 9;
+
+/// Most least significant bits a sample can give away to the payload.
+const MAX_BITS_PER_CHANNEL: u8 = 4;
+
+/// Hides `payload` in the `bits_per_channel` least significant bits of the color channels of `image`.
+///
+/// Alpha channels are left untouched so transparent areas don't change. With a `key` the samples
+/// are visited in an order only the key reproduces, header included, otherwise from the start of
+/// the image. A `hidden` slot goes in the samples `payload` left over, with the same bits per
+/// channel.
+pub(crate) fn embed(image: &mut DynamicImage, payload: &[u8], flags: PayloadFlags, bits_per_channel: u8, key: Option<&str>, hidden: Option<&HiddenSlot>) -> Result<(), StegoError> {
+    let header = Header::new(payload, flags, bits_per_channel)?;
+
+    if payload.len() as u64 > capacity(image, bits_per_channel, header.stored_len())? {
+        return Err(StegoError::CapacityExceeded);
+    }
+
+    let hidden = match hidden {
+        Some(hidden) => Some((Header::new(hidden.payload, hidden.flags, bits_per_channel)?, hidden.payload, hidden_offsets(image, key, &header, hidden.password)?)),
+        None => None,
+    };
+
+    if let Some((hidden_header, hidden_payload, _)) = &hidden {
+        let free_samples = sample_count(image)?.saturating_sub(samples_taken(&header) + header_samples(hidden_header.stored_len()));
+
+        if hidden_payload.len() as u64 > free_samples * u64::from(bits_per_channel) / 8 {
+            return Err(StegoError::CapacityExceeded);
+        }
+    }
+
+    // the capacity checks above guarantee the samples outlive the headers and the payloads
+    let mut offsets = sample_offsets(image, key)?;
+    let bytes = image_bytes_mut(image);
+
+    write_sparse(bytes, &mut offsets, &header.to_stored_bytes());
+    write_dense(bytes, &mut offsets, payload, bits_per_channel);
+
+    if let Some((hidden_header, hidden_payload, mut offsets)) = hidden {
+        write_sparse(bytes, &mut offsets, &hidden_header.to_stored_bytes());
+        write_dense(bytes, &mut offsets, hidden_payload, bits_per_channel);
+    }
+
+    Ok(())
+}
+
+/// Reads back the payload hidden by [`embed`] with the same `key`, with whatever bits per channel it was written.
+pub(crate) fn extract(image: &DynamicImage, key: Option<&str>) -> Result<(Header, Vec<u8>), StegoError> {
+    read_slot(carrier_samples(image, key)?)
+}
+
+/// Reads back the hidden slot [`embed`] put behind the payload found with `key`, if `password`
+/// is its own.
+pub(crate) fn extract_hidden(image: &DynamicImage, key: Option<&str>, password: &str) -> Result<(Header, Vec<u8>), StegoError> {
+    let decoy = container::read_header(&mut read_sparse(&mut carrier_samples(image, key)?))?;
+
+    if decoy.version == 1 {
+        return Err(StegoError::NoPayloadFound);
+    }
+
+    let bytes = image.as_bytes();
+
+    read_slot(hidden_offsets(image, key, &decoy, password)?.map(|offset| bytes[offset]))
+}
+
+/// Reads a header and the payload it announces out of `samples`.
+fn read_slot(mut samples: impl Iterator<Item = u8>) -> Result<(Header, Vec<u8>), StegoError> {
+    let header = container::read_header(&mut read_sparse(&mut samples))?;
+
+    let payload = match header.version {
+        1 => container::read_payload(&header, read_sparse(&mut samples)),
+        _ => {
+            if !(1..=MAX_BITS_PER_CHANNEL).contains(&header.bits_per_channel) {
+                return Err(StegoError::CorruptPayload(format!("{} bits per channel is out of range", header.bits_per_channel)));
+            }
+
+            container::read_payload(&header, read_dense(samples, header.bits_per_channel))
+        },
+    }?;
+
+    Ok((header, payload))
+}
+
+/// How many payload bytes [`embed`] can hide in the image with `bits_per_channel`, behind a
+/// header of `header_len` bytes.
+pub(crate) fn capacity(image: &DynamicImage, bits_per_channel: u8, header_len: usize) -> Result<u64, StegoError> {
+    if !(1..=MAX_BITS_PER_CHANNEL).contains(&bits_per_channel) {
+        return Err(StegoError::InvalidOption(format!("bits per channel must be between 1 and {MAX_BITS_PER_CHANNEL}, got {bits_per_channel}")));
+    }
+
+    Ok(sample_count(image)?.saturating_sub(header_samples(header_len)) * u64::from(bits_per_channel) / 8)
+}
+
+/// Describes the image and the header of the payload it hides, if any, without reading the payload.
+///
+/// The capacity reported is the one with a single bit per channel. Payloads hidden with a key
+/// can't be found without it, so they are reported as no payload.
+pub(crate) fn probe(image: &DynamicImage, format: CarrierFormat) -> Result<ProbeReport, StegoError> {
+    Ok(ProbeReport {
+        format,
+        width: image.width(),
+        height: image.height(),
+        capacity: capacity(image, 1, container::header_len(false))?,
+        header: container::peek(read_sparse(&mut carrier_samples(image, None)?)).map(Into::into),
+    })
+}
+
+/// Writes `bytes` one bit per sample, each byte followed by a zero, into the samples of `image`
+/// at the next `offsets`.
+fn write_sparse(image: &mut [u8], offsets: &mut impl Iterator<Item = usize>, bytes: &[u8]) {
+    for &byte in bytes {
+        for bit in (0..8).rev() {
+            set_low_bits(&mut image[offsets.next().unwrap()], byte >> bit & 1, 1);
+        }
+
+        set_low_bits(&mut image[offsets.next().unwrap()], 0, 1);
+    }
+}
+
+/// Lazily reads bytes written by [`write_sparse`].
+fn read_sparse(samples: &mut impl Iterator<Item = u8>) -> impl Iterator<Item = u8> + '_ {
+    std::iter::from_fn(move || {
+        let mut byte = 0;
+
+        for _ in 0..8 {
+            byte = byte << 1 | samples.next()? & 1;
+        }
+
+        // skip the padding bit
+        samples.next()?;
+
+        Some(byte)
+    })
+}
+
+/// Writes `bytes` as a stream of bits, most significant first, `bits` of them per sample.
+///
+/// The last sample is padded with zeros.
+fn write_dense(image: &mut [u8], offsets: &mut impl Iterator<Item = usize>, bytes: &[u8], bits: u8) {
+    let mut pending: u32 = 0;
+    let mut pending_bits = 0;
+
+    for &byte in bytes {
+        pending = pending << 8 | u32::from(byte);
+        pending_bits += 8;
+
+        while pending_bits >= bits {
+            pending_bits -= bits;
+            set_low_bits(&mut image[offsets.next().unwrap()], (pending >> pending_bits) as u8, bits);
+        }
+
+        pending &= (1 << pending_bits) - 1;
+    }
+
+    if pending_bits > 0 {
+        set_low_bits(&mut image[offsets.next().unwrap()], (pending << (bits - pending_bits)) as u8, bits);
+    }
+}
+
+/// Lazily reads bytes written by [`write_dense`].
+fn read_dense(mut samples: impl Iterator<Item = u8>, bits: u8) -> impl Iterator<Item = u8> {
+    let mask = (1 << bits) - 1;
+    let mut pending: u32 = 0;
+    let mut pending_bits = 0;
+
+    std::iter::from_fn(move || {
+        while pending_bits < 8 {
+            pending = pending << bits | u32::from(samples.next()? & mask);
+            pending_bits += bits;
+        }
+
+        pending_bits -= 8;
+        let byte = (pending >> pending_bits) as u8;
+        pending &= (1 << pending_bits) - 1;
+
+        Some(byte)
+    })
+}
+
+/// Channels per pixel and how many of them, starting from the first, carry hidden bits.
+fn layout(image: &DynamicImage) -> Result<(usize, usize), StegoError> {
+    match image {
+        DynamicImage::ImageRgb8(_) => Ok((3, 3)),
+        DynamicImage::ImageRgba8(_) => Ok((4, 3)),
+        DynamicImage::ImageLuma8(_) => Ok((1, 1)),
+        DynamicImage::ImageLumaA8(_) => Ok((2, 1)),
+        _ => Err(StegoError::WrongPixelLayout(format!("{:?} images can't carry a secret, only 8 bit RGB, RGBA, grayscale and grayscale with alpha", image.color()))),
+    }
+}
+
+/// Samples of the image that can carry hidden bits.
+fn sample_count(image: &DynamicImage) -> Result<u64, StegoError> {
+    let (_, carriers) = layout(image)?;
+
+    Ok(u64::from(image.width()) * u64::from(image.height()) * carriers as u64)
+}
+
+/// Samples taken by a header of `header_len` bytes at the start of the image.
+fn header_samples(header_len: usize) -> u64 {
+    (header_len * SPARSE_SAMPLES_PER_BYTE) as u64
+}
+
+/// Samples the payload announced by `header` takes, header included.
+fn samples_taken(header: &Header) -> u64 {
+    let length = u64::from(header.length);
+    let header_samples = header_samples(header.stored_len());
+
+    match header.version {
+        1 => header_samples + length * SPARSE_SAMPLES_PER_BYTE as u64,
+        _ => header_samples + (length * 8).div_ceil(u64::from(header.bits_per_channel.max(1))),
+    }
+}
+
+/// Offsets into the image bytes of the samples that carry hidden bits, in the order [`Scatter`]
+/// visits them for `key`.
+fn sample_offsets(image: &DynamicImage, key: Option<&str>) -> Result<impl Iterator<Item = usize> + use<>, StegoError> {
+    scattered_offsets(image, Scatter::new(sample_count(image)?, key))
+}
+
+/// Offsets of the samples the `decoy` payload found with `key` leaves over, in the order drawn
+/// from the `password` of the slot hidden behind it.
+fn hidden_offsets(image: &DynamicImage, key: Option<&str>, decoy: &Header, password: &str) -> Result<impl Iterator<Item = usize> + use<>, StegoError> {
+    let taken: HashSet<usize> = sample_offsets(image, key)?.take(samples_taken(decoy) as usize).collect();
+    let scatter = Scatter::seeded(sample_count(image)?, decoy::seed(password, decoy)?);
+
+    Ok(scattered_offsets(image, scatter)?.filter(move |offset| !taken.contains(offset)))
+}
+
+fn scattered_offsets(image: &DynamicImage, scatter: Scatter) -> Result<impl Iterator<Item = usize> + use<>, StegoError> {
+    let (channels, carriers) = layout(image)?;
+
+    Ok(scatter.map(move |sample| {
+        let sample = sample as usize;
+
+        sample / carriers * channels + sample % carriers
+    }))
+}
+
+fn carrier_samples<'a>(image: &'a DynamicImage, key: Option<&str>) -> Result<impl Iterator<Item = u8> + 'a, StegoError> {
+    let bytes = image.as_bytes();
+
+    Ok(sample_offsets(image, key)?.map(move |offset| bytes[offset]))
+}
+
+fn image_bytes_mut(image: &mut DynamicImage) -> &mut [u8] {
+    match image {
+        DynamicImage::ImageRgb8(buf) => buf,
+        DynamicImage::ImageRgba8(buf) => buf,
+        DynamicImage::ImageLuma8(buf) => buf,
+        DynamicImage::ImageLumaA8(buf) => buf,
+        // layout already rejected everything else
+        _ => unreachable!(),
+    }
+}
+
+/// Replaces the `bits` least significant bits of `sample` with the low bits of `value`.
+fn set_low_bits(sample: &mut u8, value: u8, bits: u8) {
+    let mask = (1 << bits) - 1;
+    *sample = *sample & !mask | value & mask;
+}
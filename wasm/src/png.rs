@@ -0,0 +1,44 @@
+use std::io::Cursor;
+use image::{DynamicImage, ImageFormat};
+
+use crate::{CarrierFormat, EmbedOptions, ExtractOptions, PayloadFlags, ProbeReport, StegoError};
+use crate::container::{self, Header};
+use crate::decoy::HiddenSlot;
+use crate::lsb;
+
+/// Hides `payload` in the least significant bits of an 8 bit PNG and returns the new PNG.
+///
+/// RGB, RGBA, grayscale and grayscale with alpha images keep their color type.
+pub(crate) fn embed(payload: &[u8], flags: PayloadFlags, options: &EmbedOptions, hidden: Option<&HiddenSlot>, image: &[u8]) -> Result<Vec<u8>, StegoError> {
+    let mut img = read_png(image)?;
+    lsb::embed(&mut img, payload, flags, options.bits_per_channel, options.key.as_deref(), hidden)?;
+
+    let mut ret = Vec::<u8>::new();
+    img.write_to(&mut Cursor::new(&mut ret), ImageFormat::Png)?;
+
+    Ok(ret)
+}
+
+/// Reads back the header and the payload hidden by [`embed`].
+pub(crate) fn extract(image: &[u8], options: &ExtractOptions) -> Result<(Header, Vec<u8>), StegoError> {
+    lsb::extract(&read_png(image)?, options.key.as_deref())
+}
+
+/// Reads back the slot [`embed`] hid behind a decoy, if `password` is its own.
+pub(crate) fn extract_hidden(image: &[u8], options: &ExtractOptions, password: &str) -> Result<(Header, Vec<u8>), StegoError> {
+    lsb::extract_hidden(&read_png(image)?, options.key.as_deref(), password)
+}
+
+/// How many payload bytes [`embed`] can hide in the image.
+pub(crate) fn capacity(image: &[u8], options: &EmbedOptions) -> Result<u64, StegoError> {
+    lsb::capacity(&read_png(image)?, options.bits_per_channel, container::header_len(options.error_correction > 0))
+}
+
+/// Describes the image and the header of the payload it hides, if any, without reading the payload.
+pub(crate) fn probe(image: &[u8]) -> Result<ProbeReport, StegoError> {
+    lsb::probe(&read_png(image)?, CarrierFormat::Png)
+}
+
+fn read_png(image: &[u8]) -> Result<DynamicImage, StegoError> {
+    Ok(image::load_from_memory_with_format(image, ImageFormat::Png)?)
+}
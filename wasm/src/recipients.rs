@@ -0,0 +1,172 @@
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, SharedSecret, StaticSecret};
+
+use crate::{Keypair, StegoError};
+
+const PUBLIC_KEY_PREFIX: &str = "wstg-pub-";
+const IDENTITY_PREFIX: &str = "WSTG-SECRET-KEY-";
+
+/// Keeps the keys wrapping the file key apart from anything else derived from the same secrets.
+const WRAP_INFO: &[u8] = b"wasm-steganography x25519 v1";
+
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+/// A file key encrypted to one recipient.
+const STANZA_LEN: usize = KEY_LEN + TAG_LEN;
+
+/// Bytes encrypting to `recipients` recipients adds to a payload.
+pub(crate) fn overhead(recipients: usize) -> usize {
+    KEY_LEN + 1 + recipients * STANZA_LEN + TAG_LEN
+}
+
+/// New X25519 key pair, the public key to encrypt to and the identity to decrypt with.
+pub(crate) fn generate_keypair() -> Keypair {
+    let secret = StaticSecret::from(random_key());
+
+    Keypair {
+        public_key: format_public_key(&PublicKey::from(&secret)),
+        identity: format!("{IDENTITY_PREFIX}{}", hex::encode_upper(secret.to_bytes())),
+    }
+}
+
+/// Encrypts `plaintext` so any of `recipients` can decrypt it, in the style of age.
+///
+/// A random file key encrypts the payload and is wrapped once per recipient with a key agreed
+/// between an ephemeral X25519 key and the recipient's. Layout of the result:
+///
+/// | bytes            | field                                            |
+/// |------------------|--------------------------------------------------|
+/// | 0..32            | ephemeral public key                             |
+/// | 32               | number of recipients `n`                         |
+/// | 33..33 + 48 * n  | file key wrapped for every recipient, with tag   |
+/// | 33 + 48 * n..    | ChaCha20-Poly1305 ciphertext and 16 byte tag     |
+///
+/// Recipients aren't named, whoever decrypts tries every wrapped key.
+pub(crate) fn encrypt(plaintext: &[u8], recipients: &[String]) -> Result<Vec<u8>, StegoError> {
+    let recipients = recipients.iter().map(|recipient| parse_public_key(recipient)).collect::<Result<Vec<_>, _>>()?;
+    let count = u8::try_from(recipients.len())
+        .map_err(|_| StegoError::InvalidOption(format!("at most {} recipients are supported", u8::MAX)))?;
+
+    let ephemeral = StaticSecret::from(random_key());
+    let ephemeral_public = PublicKey::from(&ephemeral);
+    let file_key = random_key();
+
+    let mut encrypted = Vec::with_capacity(overhead(recipients.len()) + plaintext.len());
+    encrypted.extend_from_slice(ephemeral_public.as_bytes());
+    encrypted.push(count);
+
+    for recipient in &recipients {
+        let shared = ephemeral.diffie_hellman(recipient);
+
+        // low order keys would agree on a secret anyone can work out
+        if !shared.was_contributory() {
+            return Err(StegoError::InvalidOption(format!("{} can't be encrypted to", format_public_key(recipient))));
+        }
+
+        encrypted.extend(seal(&wrap_key(&shared, &ephemeral_public, recipient), &file_key)?);
+    }
+
+    encrypted.extend(seal(&file_key, plaintext)?);
+
+    Ok(encrypted)
+}
+
+/// Decrypts what [`encrypt`] returned with the first of `identities` it was encrypted to.
+pub(crate) fn decrypt(encrypted: &[u8], identities: &[String]) -> Result<Vec<u8>, StegoError> {
+    if identities.is_empty() {
+        return Err(StegoError::IdentityRequired);
+    }
+
+    let identities = identities.iter().map(|identity| parse_identity(identity)).collect::<Result<Vec<_>, _>>()?;
+    let truncated = || StegoError::CorruptPayload(format!("encrypted payload of {} bytes is too short", encrypted.len()));
+
+    let (ephemeral_public, rest) = encrypted.split_first_chunk::<KEY_LEN>().ok_or_else(truncated)?;
+    let (&count, rest) = rest.split_first().ok_or_else(truncated)?;
+    let stanzas_len = usize::from(count) * STANZA_LEN;
+
+    if rest.len() < stanzas_len + TAG_LEN {
+        return Err(truncated());
+    }
+
+    let (stanzas, ciphertext) = rest.split_at(stanzas_len);
+    let ephemeral_public = PublicKey::from(*ephemeral_public);
+
+    for identity in &identities {
+        let shared = identity.diffie_hellman(&ephemeral_public);
+
+        if !shared.was_contributory() {
+            continue;
+        }
+
+        let wrap_key = wrap_key(&shared, &ephemeral_public, &PublicKey::from(identity));
+
+        for stanza in stanzas.chunks_exact(STANZA_LEN) {
+            // a stanza wrapped for someone else fails its tag
+            if let Some(file_key) = open(&wrap_key, stanza) {
+                // the CRC in the header already caught damaged payloads
+                return open(&file_key.try_into().unwrap(), ciphertext)
+                    .ok_or_else(|| StegoError::CorruptPayload("the payload doesn't match its file key".to_string()));
+            }
+        }
+    }
+
+    Err(StegoError::NotARecipient)
+}
+
+/// Key wrapping the file key for `recipient` out of the secret it shares with the ephemeral key.
+fn wrap_key(shared: &SharedSecret, ephemeral_public: &PublicKey, recipient: &PublicKey) -> [u8; KEY_LEN] {
+    let mut salt = [0u8; 2 * KEY_LEN];
+    salt[..KEY_LEN].copy_from_slice(ephemeral_public.as_bytes());
+    salt[KEY_LEN..].copy_from_slice(recipient.as_bytes());
+
+    let mut key = [0u8; KEY_LEN];
+    Hkdf::<Sha256>::new(Some(&salt), shared.as_bytes())
+        .expand(WRAP_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+    key
+}
+
+/// Encrypts with a key that is only ever used once, so the nonce can stay zero.
+fn seal(key: &[u8; KEY_LEN], plaintext: &[u8]) -> Result<Vec<u8>, StegoError> {
+    ChaCha20Poly1305::new(Key::from_slice(key))
+        .encrypt(&Nonce::default(), plaintext)
+        .map_err(|_| StegoError::CapacityExceeded)
+}
+
+fn open(key: &[u8; KEY_LEN], ciphertext: &[u8]) -> Option<Vec<u8>> {
+    ChaCha20Poly1305::new(Key::from_slice(key)).decrypt(&Nonce::default(), ciphertext).ok()
+}
+
+fn random_key() -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+
+    key
+}
+
+fn format_public_key(key: &PublicKey) -> String {
+    format!("{PUBLIC_KEY_PREFIX}{}", hex::encode(key.as_bytes()))
+}
+
+fn parse_public_key(key: &str) -> Result<PublicKey, StegoError> {
+    parse_key(key, PUBLIC_KEY_PREFIX).map(PublicKey::from)
+        .ok_or_else(|| StegoError::InvalidOption(format!("{key:?} isn't a recipient public key, they start with {PUBLIC_KEY_PREFIX}")))
+}
+
+fn parse_identity(identity: &str) -> Result<StaticSecret, StegoError> {
+    // never echo the identity back, it's a secret
+    parse_key(identity, IDENTITY_PREFIX).map(StaticSecret::from)
+        .ok_or_else(|| StegoError::InvalidOption(format!("not an identity, they start with {IDENTITY_PREFIX}")))
+}
+
+fn parse_key(key: &str, prefix: &str) -> Option<[u8; KEY_LEN]> {
+    let mut bytes = [0u8; KEY_LEN];
+    hex::decode_to_slice(key.trim().strip_prefix(prefix)?, &mut bytes).ok()?;
+
+    Some(bytes)
+}
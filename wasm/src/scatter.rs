@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
+
+/// Keeps the PRNG seeded from a stego key apart from anything else derived from the same key.
+const DOMAIN: &[u8] = b"wasm-steganography scatter v1\0";
+
+/// Visits every carrier position below `len` exactly once.
+///
+/// Without a key the positions come in ascending order. With one they come in an order drawn from
+/// a PRNG seeded with the key, so only someone holding the key knows where the payload is.
+/// The shuffle is a Fisher-Yates drawn lazily, only the positions it touched are remembered,
+/// so walking the few positions a short payload needs stays cheap in big images.
+pub(crate) struct Scatter {
+    len: u64,
+    next: u64,
+    rng: Option<ChaCha20Rng>,
+    swapped: HashMap<u64, u64>,
+}
+
+impl Scatter {
+    pub(crate) fn new(len: u64, key: Option<&str>) -> Scatter {
+        let rng = key.map(|key| {
+            let seed = Sha256::new().chain_update(DOMAIN).chain_update(key.as_bytes()).finalize();
+
+            ChaCha20Rng::from_seed(seed.into())
+        });
+
+        Scatter { len, next: 0, rng, swapped: HashMap::new() }
+    }
+
+    /// Like [`Scatter::new`] with a key, but with the PRNG seed already derived.
+    pub(crate) fn seeded(len: u64, seed: [u8; 32]) -> Scatter {
+        Scatter { len, next: 0, rng: Some(ChaCha20Rng::from_seed(seed)), swapped: HashMap::new() }
+    }
+
+    /// Order in which the 8 bits of a byte are spread over the slots of a carrier, least
+    /// significant slot first. It's the identity without a key.
+    pub(crate) fn bit_order(&mut self) -> [u8; 8] {
+        let mut order = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        if let Some(rng) = &mut self.rng {
+            for i in (1..order.len()).rev() {
+                order.swap(i, below(rng, i as u64 + 1) as usize);
+            }
+        }
+
+        order
+    }
+}
+
+impl Iterator for Scatter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.next >= self.len {
+            return None;
+        }
+
+        let i = self.next;
+        self.next += 1;
+
+        let Some(rng) = &mut self.rng else {
+            return Some(i);
+        };
+
+        // position i is never visited again, so only what ends up at j needs remembering
+        let j = i + below(rng, self.len - i);
+        let at_i = self.swapped.remove(&i).unwrap_or(i);
+
+        if j == i {
+            return Some(at_i);
+        }
+
+        Some(self.swapped.insert(j, at_i).unwrap_or(j))
+    }
+}
+
+/// Uniform value below `bound`, with a bias too small to matter for picking positions.
+fn below(rng: &mut ChaCha20Rng, bound: u64) -> u64 {
+    ((u128::from(rng.next_u64()) * u128::from(bound)) >> 64) as u64
+}
+
+/// Moves bit `i` of `byte` to bit `order[i]`.
+pub(crate) fn spread_bits(byte: u8, order: &[u8; 8]) -> u8 {
+    order.iter().enumerate().fold(0, |spread, (i, &to)| spread | (byte >> i & 1) << to)
+}
+
+/// Undoes [`spread_bits`].
+pub(crate) fn gather_bits(byte: u8, order: &[u8; 8]) -> u8 {
+    order.iter().enumerate().fold(0, |gathered, (i, &from)| gathered | (byte >> from & 1) << i)
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+
+use crate::StegoError;
+use crate::gf256::{div, mul};
+
+/// Bytes of the set ID shared by every share of a payload.
+const SET_ID_LEN: usize = 8;
+
+/// Bytes the share header takes in front of every share.
+pub(crate) const SHARE_HEADER_LEN: usize = SET_ID_LEN + 1 + 1;
+
+/// Splits `payload` in `shares` Shamir shares over GF(256), any `threshold` of which recover it
+/// while fewer tell nothing about it but its length. Every share is as long as the payload, with
+/// a share header in front:
+///
+/// | bytes | field                             |
+/// |-------|-----------------------------------|
+/// | 0..8  | random set ID                     |
+/// | 8     | share number, the point x, from 1 |
+/// | 9     | shares needed to recover it       |
+/// | 10..  | share of the payload              |
+///
+/// Every payload byte is the constant term of its own random polynomial of degree
+/// `threshold - 1`, and share x holds their values at x.
+pub(crate) fn share(payload: &[u8], threshold: u8, shares: usize) -> Result<Vec<Vec<u8>>, StegoError> {
+    let count = u8::try_from(shares).ok().filter(|&count| threshold >= 2 && threshold <= count)
+        .ok_or_else(|| StegoError::InvalidOption(format!("sharing needs between 2 and as many shares as images, up to 255, got {threshold} of {shares}")))?;
+
+    let mut set_id = [0u8; SET_ID_LEN];
+    OsRng.fill_bytes(&mut set_id);
+
+    // every coefficient but the constant term, which is the payload byte
+    let degree = usize::from(threshold) - 1;
+    let mut coefficients = vec![0u8; payload.len() * degree];
+    OsRng.fill_bytes(&mut coefficients);
+
+    Ok((1..=count).map(|x| {
+        let mut share = Vec::with_capacity(SHARE_HEADER_LEN + payload.len());
+        share.extend_from_slice(&set_id);
+        share.push(x);
+        share.push(threshold);
+        share.extend(payload.iter().zip(coefficients.chunks(degree)).map(|(&secret, coefficients)| {
+            // Horner, from the highest degree down to the constant term
+            mul(coefficients.iter().rev().fold(0, |acc, &coef| mul(acc, x) ^ coef), x) ^ secret
+        }));
+
+        share
+    }).collect())
+}
+
+/// Recovers the payload [`share`] was given from at least as many of its shares, in any order,
+/// as its threshold.
+pub(crate) fn combine(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, StegoError> {
+    let mut set = None;
+    let mut points = BTreeMap::new();
+
+    for share in &shares {
+        let (header, values) = share.split_first_chunk::<SHARE_HEADER_LEN>()
+            .ok_or_else(|| StegoError::CorruptPayload(format!("share of {} bytes is too short", share.len())))?;
+        let (set_id, rest) = header.split_first_chunk::<SET_ID_LEN>().unwrap();
+        let (x, threshold) = (rest[0], rest[1]);
+
+        if x == 0 || threshold < 2 {
+            return Err(StegoError::CorruptPayload(format!("share {x} of a threshold of {threshold} is out of range")));
+        }
+
+        if set.get_or_insert((*set_id, threshold, values.len())) != &(*set_id, threshold, values.len()) {
+            return Err(StegoError::IncompleteSplit("the images hold shares of different shared payloads".to_string()));
+        }
+
+        if points.insert(x, values).is_some() {
+            return Err(StegoError::IncompleteSplit(format!("share {x} is given more than once")));
+        }
+    }
+
+    let (_, threshold, len) = set.ok_or_else(|| StegoError::IncompleteSplit("no shares given".to_string()))?;
+
+    if points.len() < usize::from(threshold) {
+        return Err(StegoError::IncompleteSplit(format!("{} of the {threshold} shares needed", points.len())));
+    }
+
+    // Lagrange interpolation at 0, any `threshold` points define the polynomial
+    let points: Vec<(u8, &[u8])> = points.into_iter().take(usize::from(threshold)).collect();
+    let weights: Vec<u8> = points.iter().map(|&(x, _)| {
+        points.iter().filter(|&&(other, _)| other != x).fold(1, |weight, &(other, _)| mul(weight, div(other, other ^ x)))
+    }).collect();
+
+    Ok((0..len).map(|i| points.iter().zip(&weights).fold(0, |secret, (&(_, values), &weight)| secret ^ mul(values[i], weight))).collect())
+}
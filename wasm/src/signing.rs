@@ -0,0 +1,88 @@
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH, SIGNATURE_LENGTH};
+
+use crate::{PayloadFlags, SignatureStatus, SigningKeypair, StegoError};
+
+const PUBLIC_KEY_PREFIX: &str = "wstg-sig-";
+const SIGNING_KEY_PREFIX: &str = "WSTG-SIGNING-KEY-";
+
+/// Keeps these signatures from being valid for anything else signed with the same key.
+const DOMAIN: &[u8] = b"wasm-steganography signature v1\0";
+
+/// Bytes signing adds in front of a payload.
+pub(crate) const OVERHEAD: usize = PUBLIC_KEY_LENGTH + SIGNATURE_LENGTH;
+
+/// New Ed25519 key pair, the signing key to sign with and the public key receivers check.
+pub(crate) fn generate_keypair() -> SigningKeypair {
+    let mut secret = [0u8; SECRET_KEY_LENGTH];
+    OsRng.fill_bytes(&mut secret);
+    let signing_key = SigningKey::from_bytes(&secret);
+
+    SigningKeypair {
+        public_key: format_public_key(signing_key.verifying_key().as_bytes()),
+        signing_key: format!("{SIGNING_KEY_PREFIX}{}", hex::encode_upper(signing_key.to_bytes())),
+    }
+}
+
+/// Signs `payload`, as stored with `flags`, and puts the signer's public key and the signature
+/// in front of it:
+///
+/// | bytes   | field                     |
+/// |---------|---------------------------|
+/// | 0..32   | Ed25519 signer public key |
+/// | 32..96  | Ed25519 signature         |
+/// | 96..    | payload                   |
+///
+/// The flags are signed too so nobody can strip the encryption or compression they announce.
+pub(crate) fn sign(payload: &[u8], flags: PayloadFlags, signing_key: &str) -> Result<Vec<u8>, StegoError> {
+    let signing_key = parse_signing_key(signing_key)?;
+    let signature = signing_key.sign(&message(payload, flags));
+
+    let mut signed = Vec::with_capacity(OVERHEAD + payload.len());
+    signed.extend_from_slice(signing_key.verifying_key().as_bytes());
+    signed.extend_from_slice(&signature.to_bytes());
+    signed.extend_from_slice(payload);
+
+    Ok(signed)
+}
+
+/// Splits what [`sign`] returned back into the payload and whether its signature holds.
+pub(crate) fn verify(signed: &[u8], flags: PayloadFlags) -> Result<(Vec<u8>, SignatureStatus), StegoError> {
+    let (public_key, rest) = signed.split_first_chunk::<PUBLIC_KEY_LENGTH>()
+        .ok_or_else(|| StegoError::CorruptPayload(format!("signed payload of {} bytes is too short", signed.len())))?;
+    let (signature, payload) = rest.split_first_chunk::<SIGNATURE_LENGTH>()
+        .ok_or_else(|| StegoError::CorruptPayload(format!("signed payload of {} bytes is too short", signed.len())))?;
+
+    let signer = format_public_key(public_key);
+    let status = match VerifyingKey::from_bytes(public_key) {
+        Ok(key) if key.verify_strict(&message(payload, flags), &Signature::from_bytes(signature)).is_ok() => SignatureStatus::Valid(signer),
+        _ => SignatureStatus::Invalid(signer),
+    };
+
+    Ok((payload.to_vec(), status))
+}
+
+fn message(payload: &[u8], flags: PayloadFlags) -> Vec<u8> {
+    let mut message = Vec::with_capacity(DOMAIN.len() + 2 + payload.len());
+    message.extend_from_slice(DOMAIN);
+    message.extend_from_slice(&flags.bits().to_le_bytes());
+    message.extend_from_slice(payload);
+
+    message
+}
+
+fn format_public_key(key: &[u8; PUBLIC_KEY_LENGTH]) -> String {
+    format!("{PUBLIC_KEY_PREFIX}{}", hex::encode(key))
+}
+
+fn parse_signing_key(key: &str) -> Result<SigningKey, StegoError> {
+    let mut bytes = [0u8; SECRET_KEY_LENGTH];
+
+    // never echo the signing key back, it's a secret
+    key.trim().strip_prefix(SIGNING_KEY_PREFIX)
+        .and_then(|hex_key| hex::decode_to_slice(hex_key, &mut bytes).ok())
+        .ok_or_else(|| StegoError::InvalidOption(format!("not a signing key, they start with {SIGNING_KEY_PREFIX}")))?;
+
+    Ok(SigningKey::from_bytes(&bytes))
+}
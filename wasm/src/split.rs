@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+
+use crate::StegoError;
+
+/// Bytes of the set ID shared by every chunk of a payload.
+const SET_ID_LEN: usize = 8;
+
+/// Bytes the chunk header takes in front of every chunk.
+pub(crate) const CHUNK_HEADER_LEN: usize = SET_ID_LEN + 2 + 2;
+
+/// Splits `payload` in as many chunks as `capacities`, each sized in proportion to the capacity
+/// of its carrier, and puts a chunk header in front of each:
+///
+/// | bytes  | field                          |
+/// |--------|--------------------------------|
+/// | 0..8   | random set ID                  |
+/// | 8..10  | chunk index, from 0            |
+/// | 10..12 | total chunk count              |
+/// | 12..   | chunk of the payload           |
+///
+/// The index and count are little endian. `capacities` leave out the chunk header.
+pub(crate) fn split(payload: &[u8], capacities: &[u64]) -> Result<Vec<Vec<u8>>, StegoError> {
+    let total = u16::try_from(capacities.len()).ok().filter(|&total| total >= 2)
+        .ok_or_else(|| StegoError::InvalidOption(format!("a payload is split across between 2 and {} images, got {}", u16::MAX, capacities.len())))?;
+    let total_capacity: u64 = capacities.iter().sum();
+
+    if payload.len() as u64 > total_capacity {
+        return Err(StegoError::CapacityExceeded);
+    }
+
+    let mut set_id = [0u8; SET_ID_LEN];
+    OsRng.fill_bytes(&mut set_id);
+
+    // rounding every boundary down keeps each chunk within its capacity
+    let boundary = |capacity: u64| (u128::from(capacity) * payload.len() as u128 / u128::from(total_capacity.max(1))) as usize;
+    let mut start = 0;
+    let mut capacity_before = 0;
+
+    Ok(capacities.iter().enumerate().map(|(index, &capacity)| {
+        capacity_before += capacity;
+        let end = boundary(capacity_before);
+
+        let mut chunk = Vec::with_capacity(CHUNK_HEADER_LEN + end - start);
+        chunk.extend_from_slice(&set_id);
+        chunk.extend_from_slice(&(index as u16).to_le_bytes());
+        chunk.extend_from_slice(&total.to_le_bytes());
+        chunk.extend_from_slice(&payload[start..end]);
+        start = end;
+
+        chunk
+    }).collect())
+}
+
+/// Puts back together the payload [`split`] returned the chunks of, in any order.
+///
+/// Fails unless every chunk of the same set is there exactly once.
+pub(crate) fn join(chunks: Vec<Vec<u8>>) -> Result<Vec<u8>, StegoError> {
+    let mut set = None;
+    let mut pieces = BTreeMap::new();
+
+    for chunk in &chunks {
+        let (header, piece) = chunk.split_first_chunk::<CHUNK_HEADER_LEN>()
+            .ok_or_else(|| StegoError::CorruptPayload(format!("chunk of {} bytes is too short", chunk.len())))?;
+        let (set_id, rest) = header.split_first_chunk::<SET_ID_LEN>().unwrap();
+        let index = u16::from_le_bytes([rest[0], rest[1]]);
+        let total = u16::from_le_bytes([rest[2], rest[3]]);
+
+        if index >= total {
+            return Err(StegoError::CorruptPayload(format!("chunk {} of {total} is out of range", u32::from(index) + 1)));
+        }
+
+        if set.get_or_insert((*set_id, total)) != &(*set_id, total) {
+            return Err(StegoError::IncompleteSplit("the images hold chunks of different split payloads".to_string()));
+        }
+
+        if pieces.insert(index, piece).is_some() {
+            return Err(StegoError::IncompleteSplit(format!("chunk {} is given more than once", u32::from(index) + 1)));
+        }
+    }
+
+    let total = set.map_or(0, |(_, total)| total);
+    let missing: Vec<String> = (0..total).filter(|index| !pieces.contains_key(index)).map(|index| (index + 1).to_string()).collect();
+
+    if !missing.is_empty() {
+        return Err(StegoError::IncompleteSplit(format!("missing chunks {} of {total}", missing.join(", "))));
+    }
+
+    Ok(pieces.into_values().flatten().copied().collect())
+}
@@ -1,13 +1,33 @@
 use wasm_steganography::Steganography;
 use wasm_steganography::Guest;
+use wasm_steganography::StegoError;
+use wasm_steganography::{CarrierFormat, Decoy, EmbedOptions, ExtractOptions, FilePayload, HiddenContent, Keypair, PayloadFlags, SignatureStatus, SigningKeypair};
 
 const SECRET: &str = "foo";
 
+/// Bytes DEFLATE can't shrink, so they take their whole length in the carrier.
+fn incompressible(len: usize) -> Vec<u8> {
+    // xorshift64, any seed but zero works
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    (0..len).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 32) as u8
+    }).collect()
+}
+
+/// Random lowercase text, it only shrinks to about 60% of its length.
+fn incompressible_text(len: usize) -> String {
+    incompressible(len).into_iter().map(|byte| char::from(b'a' + byte % 26)).collect()
+}
+
 #[test]
 fn decode_secret_valid_bmp_returns_secret() {
     // the previously encoded image secret should be "foo"
     let bmp_bytes = std::fs::read("tests/data/test-encoded.bmp").expect("failed loading the previously encoded image");
-    let secret = Steganography::decode_secret_from_bmp(bmp_bytes.to_vec());
+    let secret = Steganography::decode_secret_from_bmp(bmp_bytes.to_vec()).expect("failed decoding the secret");
 
     assert_eq!(secret, SECRET);
 }
@@ -15,7 +35,7 @@ fn decode_secret_valid_bmp_returns_secret() {
 #[test]
 fn encode_secret_valid_bmp_returns_bmp_bytes() {
     let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
-    let encoded_bmp_bytes = Steganography::encode_secret_into_bmp("foo".to_owned(), bmp_bytes.to_vec());
+    let encoded_bmp_bytes = Steganography::encode_secret_into_bmp("foo".to_owned(), bmp_bytes.to_vec()).expect("failed encoding the secret");
     assert_ne!(&bmp_bytes, &encoded_bmp_bytes);
 
     let previosly_encoded_img = std::fs::read("tests/data/test-encoded.bmp").expect("couldn't load the encoded image");
@@ -23,11 +43,53 @@ fn encode_secret_valid_bmp_returns_bmp_bytes() {
 }
 
 #[test]
-#[should_panic]
-fn decode_secret_bmp_without_secret_panics() {
+fn decode_secret_version_1_bmp_returns_secret() {
+    // encoded before payloads were densely packed, with 3 pixels per byte
+    let bmp_bytes = std::fs::read("tests/data/test-encoded-v1.bmp").expect("failed loading the previously encoded image");
+    let secret = Steganography::decode_secret_from_bmp(bmp_bytes).expect("failed decoding the secret");
+
+    assert_eq!(secret, SECRET);
+}
+
+#[test]
+fn decode_secret_bmp_without_secret_returns_error() {
     // it has no secret encoded
     let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("failed loading the non encoded image");
-    let _secret = Steganography::decode_secret_from_bmp(bmp_bytes.to_vec());
+    let res = Steganography::decode_secret_from_bmp(bmp_bytes.to_vec());
+
+    assert!(matches!(res, Err(StegoError::NoPayloadFound)));
+}
+
+#[test]
+fn decode_bmp_with_tampered_payload_returns_corrupt_payload() {
+    let bmp_bytes = std::fs::read("tests/data/test-encoded.bmp").expect("failed loading the previously encoded image");
+    let mut img_buf = image::load_from_memory(&bmp_bytes).expect("failed decoding the bmp").to_rgb8();
+
+    // the 16 byte header takes the first 48 pixels, the payload starts right after
+    let pixel = img_buf.pixels_mut().nth(48).unwrap();
+    pixel.0[0] ^= 1;
+
+    let mut tampered = Vec::new();
+    img_buf.write_to(&mut std::io::Cursor::new(&mut tampered), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+    let res = Steganography::decode_secret_from_bmp(tampered);
+
+    assert!(matches!(res, Err(StegoError::CorruptPayload(_))));
+}
+
+#[test]
+fn encode_secret_too_long_for_bmp_returns_capacity_error() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let secret = incompressible_text(bmp_bytes.len());
+    let res = Steganography::encode_secret_into_bmp(secret, bmp_bytes);
+
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn encode_secret_into_non_image_returns_unsupported_format() {
+    let res = Steganography::encode_secret_into_bmp(SECRET.to_string(), b"not an image".to_vec());
+
+    assert!(matches!(res, Err(StegoError::UnsupportedFormat(_))));
 }
 
 #[test]
@@ -36,10 +98,1427 @@ fn decode_jpg_should_succeed() {
     let res = Steganography::encode_secret_into_jpeg(
         SECRET.to_string(),
         image,
-    );
+    ).expect("failed encoding the secret");
 
     assert!(res.is_empty() == false);
 
-    let secret = Steganography::decode_secret_from_jpeg(res);    
+    let secret = Steganography::decode_secret_from_jpeg(res).expect("failed decoding the secret");
     assert_eq!(secret, SECRET.to_string());
 }
+
+#[test]
+fn decode_jpg_without_secret_returns_error() {
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let res = Steganography::decode_secret_from_jpeg(image);
+
+    assert!(matches!(res, Err(StegoError::NoPayloadFound)));
+}
+
+#[test]
+fn encode_bytes_into_bmp_round_trips_binary_payload() {
+    // not valid utf8 on purpose
+    let payload = vec![0x00, 0xFF, 0xFE, 0x80, 0x7F];
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let encoded = Steganography::encode_bytes_into_bmp(payload.clone(), bmp_bytes).expect("failed encoding the payload");
+
+    let decoded = Steganography::decode_bytes_from_bmp(encoded.clone()).expect("failed decoding the payload");
+    assert_eq!(decoded, payload);
+
+    let res = Steganography::decode_secret_from_bmp(encoded);
+    assert!(matches!(res, Err(StegoError::InvalidUtf8)));
+}
+
+#[test]
+fn encode_bytes_into_jpg_round_trips_binary_payload() {
+    let payload = vec![0x00, 0xFF, 0xFE, 0x80, 0x7F];
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let encoded = Steganography::encode_bytes_into_jpeg(payload.clone(), image).expect("failed encoding the payload");
+
+    let decoded = Steganography::decode_bytes_from_jpeg(encoded).expect("failed decoding the payload");
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn capacity_bmp_is_exactly_what_fits() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let capacity = Steganography::capacity_bmp(bmp_bytes.clone()).expect("failed computing the capacity") as usize;
+    assert!(capacity > 0);
+
+    let payload = incompressible(capacity);
+    let encoded = Steganography::encode_bytes_into_bmp(payload.clone(), bmp_bytes.clone()).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_from_bmp(encoded).expect("failed decoding the payload"), payload);
+
+    let res = Steganography::encode_bytes_into_bmp(incompressible(capacity + 1), bmp_bytes);
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn capacity_jpg_payload_round_trips() {
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity") as usize;
+    assert!(capacity > 0);
+
+    let payload = incompressible(capacity);
+    let encoded = Steganography::encode_bytes_into_jpeg(payload.clone(), image).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_from_jpeg(encoded).expect("failed decoding the payload"), payload);
+}
+
+#[test]
+fn encode_secret_too_long_for_jpg_returns_capacity_error() {
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity") as usize;
+    let res = Steganography::encode_secret_into_jpeg(incompressible_text(2 * capacity), image);
+
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn capacity_of_non_image_returns_unsupported_format() {
+    let res = Steganography::capacity_bmp(b"not an image".to_vec());
+
+    assert!(matches!(res, Err(StegoError::UnsupportedFormat(_))));
+}
+
+#[test]
+fn probe_encoded_bmp_reports_header() {
+    let bmp_bytes = std::fs::read("tests/data/test-encoded.bmp").expect("failed loading the previously encoded image");
+    let report = Steganography::probe(bmp_bytes).expect("failed probing the image");
+
+    assert_eq!(report.format, CarrierFormat::Bmp);
+    assert_eq!((report.width, report.height), (25, 25));
+    assert_eq!(report.capacity, 216);
+
+    let header = report.header.expect("the image should carry a payload");
+    assert_eq!(header.version, 2);
+    assert_eq!(header.flags, PayloadFlags::empty());
+    assert_eq!(header.bits_per_channel, 1);
+    assert_eq!(header.length, SECRET.len() as u32);
+}
+
+#[test]
+fn probe_plain_bmp_reports_no_header() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("failed loading the non encoded image");
+    let report = Steganography::probe(bmp_bytes).expect("failed probing the image");
+
+    assert!(report.header.is_none());
+}
+
+#[test]
+fn probe_encoded_jpg_reports_header() {
+    let payload = vec![0x00, 0xFF, 0xFE, 0x80, 0x7F];
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity");
+    let encoded = Steganography::encode_bytes_into_jpeg(payload.clone(), image).expect("failed encoding the payload");
+
+    let report = Steganography::probe(encoded).expect("failed probing the image");
+    assert_eq!(report.format, CarrierFormat::Jpeg);
+    assert_eq!(report.capacity, capacity);
+
+    let header = report.header.expect("the image should carry a payload");
+    assert_eq!(header.flags, PayloadFlags::BINARY);
+    assert_eq!(header.length, payload.len() as u32);
+}
+
+#[test]
+fn probe_plain_jpg_reports_no_header() {
+    let image = std::fs::read("tests/data/test.jpeg").unwrap();
+    let report = Steganography::probe(image).expect("failed probing the image");
+
+    assert!(report.header.is_none());
+}
+
+#[test]
+fn probe_non_image_returns_unsupported_format() {
+    let res = Steganography::probe(b"not an image".to_vec());
+
+    assert!(matches!(res, Err(StegoError::UnsupportedFormat(_))));
+}
+
+/// Re-encodes the RGB test image in `format` with the given color type.
+fn test_image_as(format: image::ImageFormat, color: image::ColorType) -> Vec<u8> {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let img = image::load_from_memory(&bmp_bytes).expect("failed decoding the bmp");
+    let img = match color {
+        image::ColorType::Rgb8 => img,
+        image::ColorType::Rgba8 => image::DynamicImage::ImageRgba8(img.to_rgba8()),
+        image::ColorType::L8 => image::DynamicImage::ImageLuma8(img.to_luma8()),
+        image::ColorType::La8 => image::DynamicImage::ImageLumaA8(img.to_luma_alpha8()),
+        image::ColorType::Rgb16 => image::DynamicImage::ImageRgb16(img.to_rgb16()),
+        _ => unimplemented!(),
+    };
+
+    let mut encoded = Vec::new();
+    img.write_to(&mut std::io::Cursor::new(&mut encoded), format).expect("failed encoding the image");
+
+    encoded
+}
+
+#[test]
+fn encode_secret_into_png_round_trips_and_keeps_color_type() {
+    for color in [image::ColorType::Rgb8, image::ColorType::Rgba8, image::ColorType::L8, image::ColorType::La8] {
+        let encoded = Steganography::encode_secret_into_png(SECRET.to_string(), test_image_as(image::ImageFormat::Png, color)).expect("failed encoding the secret");
+
+        let decoded_img = image::load_from_memory_with_format(&encoded, image::ImageFormat::Png).expect("the output should be a png");
+        assert_eq!(decoded_img.color(), color);
+
+        let secret = Steganography::decode_secret_from_png(encoded).expect("failed decoding the secret");
+        assert_eq!(secret, SECRET);
+    }
+}
+
+#[test]
+fn encode_secret_into_png_leaves_alpha_untouched() {
+    let png = test_image_as(image::ImageFormat::Png, image::ColorType::Rgba8);
+    let encoded = Steganography::encode_secret_into_png(SECRET.to_string(), png.clone()).expect("failed encoding the secret");
+
+    let alpha = |png: &[u8]| image::load_from_memory(png).unwrap().to_rgba8().pixels().map(|p| p.0[3]).collect::<Vec<u8>>();
+    assert_eq!(alpha(&png), alpha(&encoded));
+}
+
+#[test]
+fn capacity_png_is_exactly_what_fits() {
+    let png = test_image_as(image::ImageFormat::Png, image::ColorType::L8);
+    let capacity = Steganography::capacity_png(png.clone()).expect("failed computing the capacity") as usize;
+    // one 8 bit sample per pixel, the header takes 9 of them per byte
+    assert_eq!(capacity, (25 * 25 - 16 * 9) / 8);
+
+    let payload = incompressible(capacity);
+    let encoded = Steganography::encode_bytes_into_png(payload.clone(), png.clone()).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_from_png(encoded).expect("failed decoding the payload"), payload);
+
+    let res = Steganography::encode_bytes_into_png(incompressible(capacity + 1), png);
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn encode_secret_into_16_bit_png_returns_wrong_pixel_layout() {
+    let res = Steganography::encode_secret_into_png(SECRET.to_string(), test_image_as(image::ImageFormat::Png, image::ColorType::Rgb16));
+
+    assert!(matches!(res, Err(StegoError::WrongPixelLayout(_))));
+}
+
+#[test]
+fn decode_secret_png_without_secret_returns_error() {
+    let png = std::fs::read("tests/data/test.png").expect("failed loading the non encoded image");
+    let res = Steganography::decode_secret_from_png(png);
+
+    assert!(matches!(res, Err(StegoError::NoPayloadFound)));
+}
+
+#[test]
+fn probe_encoded_png_reports_header() {
+    let png = std::fs::read("tests/data/test.png").expect("failed loading the non encoded image");
+    let encoded = Steganography::encode_secret_into_png(SECRET.to_string(), png).expect("failed encoding the secret");
+    let report = Steganography::probe(encoded).expect("failed probing the image");
+
+    assert_eq!(report.format, CarrierFormat::Png);
+    assert_eq!(report.capacity, 216);
+    assert_eq!(report.header.expect("the image should carry a payload").length, SECRET.len() as u32);
+}
+
+/// Bits per pixel of a BMP with a BITMAPINFOHEADER or newer.
+fn bmp_bits_per_pixel(bmp: &[u8]) -> u16 {
+    u16::from_le_bytes([bmp[28], bmp[29]])
+}
+
+#[test]
+fn encode_secret_into_bmp_keeps_its_layout() {
+    for (color, bits_per_pixel) in [(image::ColorType::Rgba8, 32), (image::ColorType::L8, 8)] {
+        let bmp_bytes = test_image_as(image::ImageFormat::Bmp, color);
+        assert_eq!(bmp_bits_per_pixel(&bmp_bytes), bits_per_pixel);
+
+        let encoded = Steganography::encode_secret_into_bmp(SECRET.to_string(), bmp_bytes).expect("failed encoding the secret");
+        assert_eq!(bmp_bits_per_pixel(&encoded), bits_per_pixel);
+
+        let secret = Steganography::decode_secret_from_bmp(encoded).expect("failed decoding the secret");
+        assert_eq!(secret, SECRET);
+    }
+}
+
+#[test]
+fn capacity_grayscale_bmp_uses_one_sample_per_pixel() {
+    let bmp_bytes = test_image_as(image::ImageFormat::Bmp, image::ColorType::L8);
+    let capacity = Steganography::capacity_bmp(bmp_bytes).expect("failed computing the capacity");
+
+    assert_eq!(capacity, (25 * 25 - 16 * 9) / 8);
+}
+
+#[test]
+fn more_bits_per_channel_hold_more_bytes() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    for bits_per_channel in 1..=4 {
+        let options = EmbedOptions { bits_per_channel, ..Default::default() };
+        let capacity = Steganography::capacity_with_options(bmp_bytes.clone(), options.clone()).expect("failed computing the capacity");
+        assert_eq!(capacity, (25 * 25 * 3 - 16 * 9) * u64::from(bits_per_channel) / 8);
+
+        let payload = incompressible(capacity as usize);
+        let encoded = Steganography::encode_bytes_with_options(payload.clone(), bmp_bytes.clone(), options.clone()).expect("a payload of the reported capacity should fit");
+        assert_eq!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().bits_per_channel, bits_per_channel);
+        assert_eq!(Steganography::decode_bytes_from_bmp(encoded).expect("failed decoding the payload"), payload);
+
+        let res = Steganography::encode_bytes_with_options(incompressible(capacity as usize + 1), bmp_bytes.clone(), options);
+        assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+    }
+}
+
+#[test]
+fn encode_secret_with_options_keeps_the_carrier_format() {
+    let options = EmbedOptions { bits_per_channel: 3, ..Default::default() };
+    let png = std::fs::read("tests/data/test.png").expect("failed loading the non encoded image");
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), png, options).expect("failed encoding the secret");
+
+    assert_eq!(image::guess_format(&encoded).unwrap(), image::ImageFormat::Png);
+    assert_eq!(Steganography::decode_secret_from_png(encoded).expect("failed decoding the secret"), SECRET);
+}
+
+#[test]
+fn bits_per_channel_out_of_range_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    for bits_per_channel in [0, 5] {
+        let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), EmbedOptions { bits_per_channel, ..Default::default() });
+
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+    }
+}
+
+fn keyed(key: &str) -> (EmbedOptions, ExtractOptions) {
+    (EmbedOptions { key: Some(key.to_string()), ..Default::default() }, ExtractOptions { key: Some(key.to_string()), password: None, identities: Vec::new() })
+}
+
+#[test]
+fn keyed_secret_round_trips_only_with_its_key() {
+    let (embed_options, extract_options) = keyed("correct horse");
+    let (_, wrong_key) = keyed("battery staple");
+    let carriers = [
+        std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image"),
+        std::fs::read("tests/data/test.png").expect("couldn't read the non encoded image"),
+        std::fs::read("tests/data/test.jpeg").expect("couldn't read the non encoded image"),
+    ];
+
+    for image in carriers {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, embed_options.clone()).expect("failed encoding the secret");
+
+        let secret = Steganography::decode_secret_with_options(encoded.clone(), extract_options.clone()).expect("failed decoding the secret").secret;
+        assert_eq!(secret, SECRET);
+
+        let res = Steganography::decode_secret_with_options(encoded.clone(), ExtractOptions { key: None, password: None, identities: Vec::new() });
+        assert!(matches!(res, Err(StegoError::NoPayloadFound)));
+
+        let res = Steganography::decode_secret_with_options(encoded.clone(), wrong_key.clone());
+        assert!(matches!(res, Err(StegoError::NoPayloadFound)));
+
+        assert!(Steganography::probe(encoded).unwrap().header.is_none());
+    }
+}
+
+#[test]
+fn keyed_payload_of_full_capacity_round_trips() {
+    let (embed_options, extract_options) = keyed("correct horse");
+
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let capacity = Steganography::capacity_with_options(image.clone(), embed_options.clone()).expect("failed computing the capacity");
+        let payload = incompressible(capacity as usize);
+
+        let encoded = Steganography::encode_bytes_with_options(payload.clone(), image, embed_options.clone()).expect("a payload of the reported capacity should fit");
+        let decoded = Steganography::decode_bytes_with_options(encoded, extract_options.clone()).expect("failed decoding the payload").payload;
+
+        assert_eq!(decoded, payload);
+    }
+}
+
+#[test]
+fn key_spreads_the_payload_over_the_image() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let pixels = |bmp: &[u8]| image::load_from_memory(bmp).unwrap().into_rgb8().into_raw();
+    let original = pixels(&bmp_bytes);
+    let last_changed_sample = |encoded: Vec<u8>| original.iter().zip(&pixels(&encoded)).rposition(|(a, b)| a != b).unwrap();
+    let (embed_options, _) = keyed("correct horse");
+
+    let sequential = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), EmbedOptions::default()).unwrap();
+    let scattered = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), embed_options).unwrap();
+
+    // without a key the header and the secret take the first samples
+    assert!(last_changed_sample(sequential) < 16 * 9 + SECRET.len() * 8);
+    assert!(last_changed_sample(scattered) > original.len() / 2);
+}
+
+fn with_password(password: &str) -> (EmbedOptions, ExtractOptions) {
+    (EmbedOptions { password: Some(password.to_string()), ..Default::default() }, ExtractOptions { key: None, password: Some(password.to_string()), identities: Vec::new() })
+}
+
+#[test]
+fn encrypted_secret_round_trips_only_with_its_password() {
+    let (embed_options, extract_options) = with_password("hunter2");
+    let (_, wrong_password) = with_password("hunter3");
+
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, embed_options.clone()).expect("failed encoding the secret");
+
+        let header = Steganography::probe(encoded.clone()).unwrap().header.expect("the header isn't encrypted");
+        assert_eq!(header.flags, PayloadFlags::ENCRYPTED);
+        assert_eq!(header.length as usize, SECRET.len() + 56);
+
+        let secret = Steganography::decode_secret_with_options(encoded.clone(), extract_options.clone()).expect("failed decrypting the secret").secret;
+        assert_eq!(secret, SECRET);
+
+        let res = Steganography::decode_secret_with_options(encoded.clone(), wrong_password.clone());
+        assert!(matches!(res, Err(StegoError::WrongPassword)));
+
+        let res = Steganography::decode_secret_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() });
+        assert!(matches!(res, Err(StegoError::PasswordRequired)));
+    }
+}
+
+#[test]
+fn decode_encrypted_secret_without_options_returns_password_required() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (embed_options, _) = with_password("hunter2");
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, embed_options).expect("failed encoding the secret");
+
+    let res = Steganography::decode_secret_from_bmp(encoded);
+
+    assert!(matches!(res, Err(StegoError::PasswordRequired)));
+}
+
+#[test]
+fn capacity_with_password_leaves_room_for_the_encryption() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (embed_options, extract_options) = with_password("hunter2");
+
+    let capacity = Steganography::capacity_with_options(bmp_bytes.clone(), embed_options.clone()).expect("failed computing the capacity");
+    assert_eq!(capacity, Steganography::capacity_bmp(bmp_bytes.clone()).unwrap() - 56);
+
+    let payload = incompressible(capacity as usize);
+    let encoded = Steganography::encode_bytes_with_options(payload.clone(), bmp_bytes.clone(), embed_options.clone()).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_with_options(encoded, extract_options).expect("failed decrypting the payload").payload, payload);
+
+    let res = Steganography::encode_bytes_with_options(incompressible(capacity as usize + 1), bmp_bytes, embed_options);
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn empty_password_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (embed_options, _) = with_password("");
+
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, embed_options);
+
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+fn to_recipients(keypairs: &[&Keypair]) -> EmbedOptions {
+    EmbedOptions { recipients: keypairs.iter().map(|keypair| keypair.public_key.clone()).collect(), ..Default::default() }
+}
+
+fn with_identity(keypair: &Keypair) -> ExtractOptions {
+    ExtractOptions { key: None, password: None, identities: vec![keypair.identity.clone()] }
+}
+
+#[test]
+fn secret_encrypted_to_recipients_round_trips_for_each_of_them() {
+    let (alice, bob, eve) = (Steganography::generate_keypair(), Steganography::generate_keypair(), Steganography::generate_keypair());
+    assert!(alice.public_key.starts_with("wstg-pub-"));
+    assert!(alice.identity.starts_with("WSTG-SECRET-KEY-"));
+
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, to_recipients(&[&alice, &bob])).expect("failed encoding the secret");
+
+        let header = Steganography::probe(encoded.clone()).unwrap().header.unwrap();
+        assert_eq!(header.flags, PayloadFlags::RECIPIENTS);
+        assert_eq!(header.length as usize, SECRET.len() + 32 + 1 + 2 * 48 + 16);
+
+        for recipient in [&alice, &bob] {
+            let secret = Steganography::decode_secret_with_options(encoded.clone(), with_identity(recipient)).expect("failed decrypting the secret").secret;
+            assert_eq!(secret, SECRET);
+        }
+
+        let res = Steganography::decode_secret_with_options(encoded.clone(), with_identity(&eve));
+        assert!(matches!(res, Err(StegoError::NotARecipient)));
+
+        let res = Steganography::decode_secret_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() });
+        assert!(matches!(res, Err(StegoError::IdentityRequired)));
+    }
+}
+
+#[test]
+fn any_of_several_identities_decrypts() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (alice, eve) = (Steganography::generate_keypair(), Steganography::generate_keypair());
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, to_recipients(&[&alice])).expect("failed encoding the secret");
+
+    let options = ExtractOptions { key: None, password: None, identities: vec![eve.identity, alice.identity] };
+
+    assert_eq!(Steganography::decode_secret_with_options(encoded, options).expect("failed decrypting the secret").secret, SECRET);
+}
+
+#[test]
+fn capacity_with_recipients_leaves_room_for_their_keys() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let alice = Steganography::generate_keypair();
+    let options = to_recipients(&[&alice]);
+
+    let capacity = Steganography::capacity_with_options(bmp_bytes.clone(), options.clone()).expect("failed computing the capacity");
+    assert_eq!(capacity, Steganography::capacity_bmp(bmp_bytes.clone()).unwrap() - (32 + 1 + 48 + 16));
+
+    let payload = incompressible(capacity as usize);
+    let encoded = Steganography::encode_bytes_with_options(payload.clone(), bmp_bytes, options).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_with_options(encoded, with_identity(&alice)).expect("failed decrypting the payload").payload, payload);
+}
+
+#[test]
+fn malformed_recipient_or_both_kinds_of_encryption_return_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let alice = Steganography::generate_keypair();
+
+    let options = EmbedOptions { recipients: vec!["wstg-pub-nothex".to_string()], ..Default::default() };
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), options);
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+
+    // the identity is a secret, it's not a recipient
+    let options = EmbedOptions { recipients: vec![alice.identity.clone()], ..Default::default() };
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), options);
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+
+    let options = EmbedOptions { password: Some("hunter2".to_string()), ..to_recipients(&[&alice]) };
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, options);
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+fn signed_by(keypair: &SigningKeypair) -> EmbedOptions {
+    EmbedOptions { signing_key: Some(keypair.signing_key.clone()), ..Default::default() }
+}
+
+#[test]
+fn signed_secret_reports_its_signer() {
+    let alice = Steganography::generate_signing_keypair();
+    assert!(alice.public_key.starts_with("wstg-sig-"));
+    assert!(alice.signing_key.starts_with("WSTG-SIGNING-KEY-"));
+
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, signed_by(&alice)).expect("failed encoding the secret");
+
+        let header = Steganography::probe(encoded.clone()).unwrap().header.unwrap();
+        assert_eq!(header.flags, PayloadFlags::SIGNED);
+        assert_eq!(header.length as usize, SECRET.len() + 32 + 64);
+
+        let decoded = Steganography::decode_secret_with_options(encoded.clone(), ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+        assert_eq!(decoded.secret, SECRET);
+        assert!(matches!(decoded.signature, SignatureStatus::Valid(signer) if signer == alice.public_key));
+    }
+}
+
+#[test]
+fn unsigned_secret_reports_unsigned() {
+    let bmp_bytes = std::fs::read("tests/data/test-encoded.bmp").expect("failed loading the previously encoded image");
+
+    let decoded = Steganography::decode_secret_with_options(bmp_bytes, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+
+    assert_eq!(decoded.secret, SECRET);
+    assert!(matches!(decoded.signature, SignatureStatus::Unsigned));
+}
+
+/// Rewrites the payload hidden without a key in a BMP with 1 bit per channel and its flags, fixing
+/// the rest of the header so only what's inside the payload can tell.
+fn rewrite_bmp_payload(bmp: &[u8], rewrite: impl FnOnce(&mut PayloadFlags, &mut Vec<u8>)) -> Vec<u8> {
+    let mut img_buf = image::load_from_memory(bmp).expect("failed decoding the bmp").to_rgb8();
+    let samples: &mut [u8] = &mut img_buf;
+    let read_byte = |bits: &[u8]| bits.iter().fold(0u8, |byte, sample| byte << 1 | sample & 1);
+    let write_byte = |bits: &mut [u8], byte: u8| bits.iter_mut().enumerate().for_each(|(i, sample)| *sample = *sample & !1 | byte >> (7 - i) & 1);
+
+    // the header bytes take 9 samples each, the payload packs 8 bits in 8 samples after it
+    let (header, payload_samples) = samples.split_at_mut(16 * 9);
+    let length = u32::from_le_bytes(std::array::from_fn(|i| read_byte(&header[(8 + i) * 9..][..8]))) as usize;
+    let mut flags = PayloadFlags::from_bits(read_byte(&header[5 * 9..][..8]).into()).unwrap();
+    let mut payload: Vec<u8> = payload_samples[..length * 8].chunks(8).map(read_byte).collect();
+
+    rewrite(&mut flags, &mut payload);
+    assert!(payload.len() * 8 <= payload_samples.len(), "the rewritten payload doesn't fit");
+
+    for (bits, &byte) in payload_samples.chunks_mut(8).zip(&payload) {
+        write_byte(bits, byte);
+    }
+
+    write_byte(&mut header[5 * 9..][..8], flags.bits() as u8);
+
+    for (i, byte) in (payload.len() as u32).to_le_bytes().into_iter().chain(crc32fast::hash(&payload).to_le_bytes()).enumerate() {
+        write_byte(&mut header[(8 + i) * 9..][..8], byte);
+    }
+
+    let mut rewritten = Vec::new();
+    img_buf.write_to(&mut std::io::Cursor::new(&mut rewritten), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+
+    rewritten
+}
+
+/// Raw bytes of a `wstg-sig-` public key.
+fn public_key_bytes(keypair: &SigningKeypair) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    hex::decode_to_slice(keypair.public_key.strip_prefix("wstg-sig-").unwrap(), &mut key).unwrap();
+
+    key
+}
+
+#[test]
+fn tampered_signed_secret_reports_invalid_signature() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (alice, mallory) = (Steganography::generate_signing_keypair(), Steganography::generate_signing_keypair());
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), signed_by(&alice)).expect("failed encoding the secret");
+    let forged = Steganography::encode_secret_with_options("bar".to_string(), bmp_bytes, signed_by(&mallory)).expect("failed encoding the secret");
+
+    // the secret changes after it was signed
+    let tampered = rewrite_bmp_payload(&encoded, |_, payload| payload[96] = b'g');
+    let decoded = Steganography::decode_secret_with_options(tampered, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+    assert_eq!(decoded.secret, "goo");
+    assert!(matches!(decoded.signature, SignatureStatus::Invalid(signer) if signer == alice.public_key));
+
+    // someone else's signature claims to be alice's
+    let tampered = rewrite_bmp_payload(&forged, |_, payload| payload[..32].copy_from_slice(&public_key_bytes(&alice)));
+    let decoded = Steganography::decode_secret_with_options(tampered, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+    assert!(matches!(decoded.signature, SignatureStatus::Invalid(signer) if signer == alice.public_key));
+}
+
+#[test]
+fn signed_and_encrypted_secret_round_trips() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let alice = Steganography::generate_signing_keypair();
+    let (embed_options, extract_options) = with_password("hunter2");
+    let embed_options = EmbedOptions { signing_key: Some(alice.signing_key), ..embed_options };
+
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, embed_options).expect("failed encoding the secret");
+    assert_eq!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().flags, PayloadFlags::ENCRYPTED | PayloadFlags::SIGNED);
+
+    let decoded = Steganography::decode_secret_with_options(encoded.clone(), extract_options).expect("failed decrypting the secret");
+    assert_eq!(decoded.secret, SECRET);
+    assert!(matches!(decoded.signature, SignatureStatus::Valid(signer) if signer == alice.public_key));
+
+    // the signature is checked before decrypting
+    let res = Steganography::decode_secret_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() });
+    assert!(matches!(res, Err(StegoError::PasswordRequired)));
+}
+
+#[test]
+fn capacity_with_signing_key_leaves_room_for_the_signature() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let options = signed_by(&Steganography::generate_signing_keypair());
+
+    let capacity = Steganography::capacity_with_options(bmp_bytes.clone(), options.clone()).expect("failed computing the capacity");
+    assert_eq!(capacity, Steganography::capacity_bmp(bmp_bytes.clone()).unwrap() - (32 + 64));
+
+    let res = Steganography::encode_bytes_with_options(incompressible(capacity as usize + 1), bmp_bytes, options);
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+#[test]
+fn malformed_signing_key_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let options = EmbedOptions { signing_key: Some("WSTG-SIGNING-KEY-nothex".to_string()), ..Default::default() };
+
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, options);
+
+    assert!(matches!(res, Err(StegoError::InvalidOption(message)) if !message.contains("nothex")));
+}
+
+#[test]
+fn compressible_secret_longer_than_the_capacity_round_trips() {
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let capacity = Steganography::capacity_with_options(image.clone(), EmbedOptions::default()).expect("failed computing the capacity") as usize;
+        let secret = "foo bar ".repeat(capacity);
+
+        let encoded = Steganography::encode_secret_with_options(secret.clone(), image, EmbedOptions::default()).expect("a compressible secret should fit");
+
+        let header = Steganography::probe(encoded.clone()).unwrap().header.unwrap();
+        assert_eq!(header.flags, PayloadFlags::COMPRESSED);
+        assert!((header.length as usize) < capacity);
+
+        let decoded = Steganography::decode_secret_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+        assert_eq!(decoded.secret, secret);
+    }
+}
+
+#[test]
+fn compressed_secret_round_trips_encrypted_and_signed() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let alice = Steganography::generate_signing_keypair();
+    let (embed_options, extract_options) = with_password("hunter2");
+    let embed_options = EmbedOptions { signing_key: Some(alice.signing_key), ..embed_options };
+    let secret = "foo bar ".repeat(100);
+
+    let encoded = Steganography::encode_secret_with_options(secret.clone(), bmp_bytes, embed_options).expect("failed encoding the secret");
+    assert_eq!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().flags, PayloadFlags::COMPRESSED | PayloadFlags::ENCRYPTED | PayloadFlags::SIGNED);
+
+    let decoded = Steganography::decode_secret_with_options(encoded, extract_options).expect("failed decrypting the secret");
+    assert_eq!(decoded.secret, secret);
+    assert!(matches!(decoded.signature, SignatureStatus::Valid(signer) if signer == alice.public_key));
+}
+
+#[test]
+fn incompressible_payload_is_stored_uncompressed() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let payload = incompressible(100);
+
+    let encoded = Steganography::encode_bytes_into_bmp(payload.clone(), bmp_bytes).expect("failed encoding the payload");
+
+    let header = Steganography::probe(encoded.clone()).unwrap().header.unwrap();
+    assert_eq!(header.flags, PayloadFlags::BINARY);
+    assert_eq!(header.length, 100);
+    assert_eq!(Steganography::decode_bytes_from_bmp(encoded).expect("failed decoding the payload"), payload);
+}
+
+#[test]
+fn decompression_bomb_returns_corrupt_payload() {
+    // room for the 16 KiB of DEFLATE data inflating to 17 MiB
+    let mut carrier = Vec::new();
+    image::RgbImage::new(256, 256).write_to(&mut std::io::Cursor::new(&mut carrier), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+    let encoded = Steganography::encode_bytes_into_bmp(vec![0], carrier).expect("failed encoding the payload");
+    let bomb = miniz_oxide::deflate::compress_to_vec(&vec![0; 17 * 1024 * 1024], 10);
+
+    let tampered = rewrite_bmp_payload(&encoded, |flags, payload| {
+        *flags |= PayloadFlags::COMPRESSED;
+        *payload = bomb;
+    });
+    let res = Steganography::decode_bytes_from_bmp(tampered);
+
+    assert!(matches!(res, Err(StegoError::CorruptPayload(reason)) if reason.contains("inflates past")));
+}
+
+fn with_error_correction(parity: u8) -> EmbedOptions {
+    EmbedOptions { error_correction: parity, ..Default::default() }
+}
+
+/// Flips the least significant bit of the given color samples of a BMP, as a lossy edit would.
+fn damage_bmp(bmp: &[u8], samples: &[usize]) -> Vec<u8> {
+    let mut img_buf = image::load_from_memory(bmp).expect("failed decoding the bmp").to_rgb8();
+
+    for &sample in samples {
+        img_buf.as_mut()[sample] ^= 1;
+    }
+
+    let mut damaged = Vec::new();
+    img_buf.write_to(&mut std::io::Cursor::new(&mut damaged), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+
+    damaged
+}
+
+#[test]
+fn error_corrected_secret_survives_damaged_bytes() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, with_error_correction(8)).expect("failed encoding the secret");
+    assert_eq!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().flags, PayloadFlags::ERROR_CORRECTED);
+
+    // the payload takes 8 samples per byte after the 432 of the three header copies: three copies
+    // of the parity count, then the block with the secret and its 8 parity bytes
+    let payload_byte = |index: usize| 48 * 9 + index * 8 + 3;
+    let damaged = damage_bmp(&encoded, &[payload_byte(1), payload_byte(3), payload_byte(7), payload_byte(13)]);
+
+    let res = Steganography::decode_secret_from_bmp(damaged.clone());
+    assert_eq!(res.expect("failed correcting the secret"), SECRET);
+
+    let decoded = Steganography::decode_secret_with_options(damaged, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed correcting the secret");
+    assert_eq!(decoded.secret, SECRET);
+    assert_eq!(decoded.corrected_errors, 4);
+}
+
+#[test]
+fn error_correction_repairs_half_its_parity_in_every_block() {
+    let mut carrier = Vec::new();
+    image::RgbImage::new(128, 128).write_to(&mut std::io::Cursor::new(&mut carrier), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+    let payload = incompressible(2000);
+    let encoded = Steganography::encode_bytes_with_options(payload.clone(), carrier, with_error_correction(16)).expect("failed encoding the payload");
+
+    // 8 damaged bytes spread over each of the 255 byte blocks, the last one is shorter
+    let damaged_bytes: Vec<usize> = (0..2000usize.div_ceil(239))
+        .flat_map(|block| (0..8).map(move |i| 3 + block * 255 + (i * 31 + block * 7) % 100))
+        .collect();
+    let damaged = damage_bmp(&encoded, &damaged_bytes.iter().map(|byte| 48 * 9 + byte * 8).collect::<Vec<_>>());
+
+    let decoded = Steganography::decode_bytes_with_options(damaged, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed correcting the payload");
+    assert_eq!(decoded.payload, payload);
+    assert_eq!(decoded.corrected_errors as usize, damaged_bytes.len());
+}
+
+#[test]
+fn error_corrected_secret_damaged_past_its_parity_returns_corrupt_payload() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, with_error_correction(4)).expect("failed encoding the secret");
+
+    let payload_byte = |index: usize| 48 * 9 + index * 8;
+    let damaged = damage_bmp(&encoded, &[payload_byte(3), payload_byte(4), payload_byte(5)]);
+    let res = Steganography::decode_secret_from_bmp(damaged);
+
+    assert!(matches!(res, Err(StegoError::CorruptPayload(_))));
+}
+
+#[test]
+fn error_corrected_secret_survives_a_damaged_header() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, with_error_correction(32)).expect("failed encoding the secret");
+    assert_eq!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().version, 4);
+
+    // the header takes 9 samples per byte, most significant bit first, and is stored three times
+    let header_bit = |copy: usize, byte: usize, bit: usize| (copy * 16 + byte) * 9 + bit;
+
+    // the magic value, the version, the error correction flag, the length and the checksum
+    for (byte, bit) in [(0, 7), (4, 5), (5, 2), (8, 0), (12, 3)] {
+        for copy in 0..3 {
+            let damaged = damage_bmp(&encoded, &[header_bit(copy, byte, bit)]);
+
+            let res = Steganography::decode_secret_from_bmp(damaged);
+            assert_eq!(res.expect("the other copies should outvote the damaged one"), SECRET, "copy {copy}, byte {byte}, bit {bit}");
+        }
+    }
+
+    // a checksum outvoted by the same damage to two copies doesn't lose blocks that are intact
+    let damaged = damage_bmp(&encoded, &[header_bit(0, 12, 3), header_bit(1, 12, 3)]);
+    assert_eq!(Steganography::decode_secret_from_bmp(damaged).expect("failed decoding the secret"), SECRET);
+}
+
+#[test]
+fn error_corrected_secret_round_trips_undamaged() {
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, with_error_correction(32)).expect("failed encoding the secret");
+
+        let decoded = Steganography::decode_secret_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the secret");
+        assert_eq!(decoded.secret, SECRET);
+        assert_eq!(decoded.corrected_errors, 0);
+    }
+}
+
+#[test]
+fn capacity_with_error_correction_leaves_room_for_the_parity() {
+    // two more copies of the header, 9 samples per byte in a BMP and a block per byte in a JPEG
+    for (image, header_copies) in [(std::fs::read("tests/data/test.bmp").unwrap(), 2 * 16 * 9 / 8), (std::fs::read("tests/data/test.jpeg").unwrap(), 2 * 16)] {
+        let capacity = Steganography::capacity_with_options(image.clone(), with_error_correction(16)).expect("failed computing the capacity");
+        let raw_capacity = Steganography::capacity_with_options(image.clone(), EmbedOptions::default()).unwrap() - header_copies;
+        // three copies of the parity count and 16 parity bytes per started block of 255 bytes
+        assert_eq!(capacity, raw_capacity - 3 - (raw_capacity - 3).div_ceil(255) * 16);
+
+        let payload = incompressible(capacity as usize);
+        let encoded = Steganography::encode_bytes_with_options(payload.clone(), image.clone(), with_error_correction(16)).expect("a payload of the reported capacity should fit");
+        assert_eq!(Steganography::decode_bytes_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the payload").payload, payload);
+
+        let res = Steganography::encode_bytes_with_options(incompressible(capacity as usize + 1), image, with_error_correction(16));
+        assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+    }
+}
+
+#[test]
+fn error_correction_out_of_range_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), with_error_correction(129));
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+
+    let res = Steganography::capacity_with_options(bmp_bytes, with_error_correction(129));
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+fn report_file() -> FilePayload {
+    FilePayload { name: "report.pdf".to_string(), media_type: "application/pdf".to_string(), content: incompressible(40) }
+}
+
+#[test]
+fn file_round_trips_with_its_name_and_media_type() {
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_file_with_options(report_file(), image, EmbedOptions::default()).expect("failed encoding the file");
+
+        let decoded = Steganography::decode_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the file");
+        assert!(matches!(decoded.content, HiddenContent::File(file) if file.name == "report.pdf" && file.media_type == "application/pdf" && file.content == incompressible(40)));
+    }
+}
+
+#[test]
+fn decode_with_options_tells_text_from_bytes() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let options = || ExtractOptions { key: None, password: None, identities: Vec::new() };
+
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), EmbedOptions::default()).unwrap();
+    assert!(matches!(Steganography::decode_with_options(encoded, options()).unwrap().content, HiddenContent::Text(secret) if secret == SECRET));
+
+    let encoded = Steganography::encode_bytes_with_options(SECRET.as_bytes().to_vec(), bmp_bytes, EmbedOptions::default()).unwrap();
+    assert!(matches!(Steganography::decode_with_options(encoded, options()).unwrap().content, HiddenContent::Binary(payload) if payload == SECRET.as_bytes()));
+}
+
+#[test]
+fn decode_bytes_of_a_file_returns_its_content() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (embed_options, extract_options) = with_password("hunter2");
+    let encoded = Steganography::encode_file_with_options(report_file(), bmp_bytes, embed_options).expect("failed encoding the file");
+
+    let decoded = Steganography::decode_bytes_with_options(encoded.clone(), extract_options).expect("failed decoding the file");
+    assert_eq!(decoded.payload, incompressible(40));
+    assert_eq!(Steganography::probe(encoded).unwrap().header.unwrap().flags, PayloadFlags::FILE | PayloadFlags::ENCRYPTED);
+}
+
+#[test]
+fn file_name_with_folders_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    for name in ["", "..", "../etc/passwd", "C:\\report.pdf"] {
+        let file = FilePayload { name: name.to_string(), ..report_file() };
+        let res = Steganography::encode_file_with_options(file, bmp_bytes.clone(), EmbedOptions::default());
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))), "{name:?} should be rejected");
+    }
+
+    let file = FilePayload { media_type: "pdf".to_string(), ..report_file() };
+    let res = Steganography::encode_file_with_options(file, bmp_bytes, EmbedOptions::default());
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+fn note_and_attachment() -> Vec<FilePayload> {
+    vec![
+        FilePayload { name: "note.txt".to_string(), media_type: "text/plain".to_string(), content: b"see attached".to_vec() },
+        FilePayload { name: "attachment.bin".to_string(), media_type: "application/octet-stream".to_string(), content: incompressible(30) },
+    ]
+}
+
+#[test]
+fn archive_round_trips_every_file_in_order() {
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap()] {
+        let encoded = Steganography::encode_archive_with_options(note_and_attachment(), image, EmbedOptions::default()).expect("failed encoding the archive");
+        assert!(Steganography::probe(encoded.clone()).unwrap().header.unwrap().flags.contains(PayloadFlags::ARCHIVE));
+
+        let decoded = Steganography::decode_with_options(encoded, ExtractOptions { key: None, password: None, identities: Vec::new() }).expect("failed decoding the archive");
+        let HiddenContent::Archive(files) = decoded.content else { panic!("expected an archive") };
+        let expected = note_and_attachment();
+        assert_eq!(files.len(), 2);
+
+        for (file, expected) in files.iter().zip(&expected) {
+            assert_eq!((&file.name, &file.media_type, &file.content), (&expected.name, &expected.media_type, &expected.content));
+        }
+    }
+}
+
+#[test]
+fn archive_with_a_repeated_name_or_no_files_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let mut files = note_and_attachment();
+    files[1].name = files[0].name.clone();
+
+    let res = Steganography::encode_archive_with_options(files, bmp_bytes.clone(), EmbedOptions::default());
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+
+    let res = Steganography::encode_archive_with_options(Vec::new(), bmp_bytes, EmbedOptions::default());
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+#[test]
+fn archive_with_sizes_past_its_content_returns_corrupt_payload() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let encoded = Steganography::encode_archive_with_options(note_and_attachment(), bmp_bytes, EmbedOptions::default()).expect("failed encoding the archive");
+
+    // drops the last content byte, the directory still claims it
+    let tampered = rewrite_bmp_payload(&encoded, |flags, payload| {
+        assert_eq!(*flags, PayloadFlags::ARCHIVE);
+        payload.pop();
+    });
+
+    let res = Steganography::decode_with_options(tampered, ExtractOptions { key: None, password: None, identities: Vec::new() });
+    assert!(matches!(res, Err(StegoError::CorruptPayload(_))));
+}
+
+fn no_extract_options() -> ExtractOptions {
+    ExtractOptions { key: None, password: None, identities: Vec::new() }
+}
+
+#[test]
+fn split_payload_longer_than_one_image_joins_back_in_any_order() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let jpeg_bytes = std::fs::read("tests/data/test.jpeg").expect("couldn't read the non encoded image");
+    let bmp_capacity = Steganography::capacity_bmp(bmp_bytes.clone()).unwrap();
+    let payload = incompressible(bmp_capacity as usize + 20);
+
+    let images = Steganography::encode_split_with_options(HiddenContent::Binary(payload.clone()), vec![bmp_bytes, jpeg_bytes], EmbedOptions::default()).expect("failed splitting the payload");
+    assert_eq!(images.len(), 2);
+
+    for image in &images {
+        let header = Steganography::probe(image.clone()).unwrap().header.expect("every image should hold a chunk");
+        assert_eq!(header.version, 3);
+        assert_eq!(header.flags, PayloadFlags::BINARY | PayloadFlags::SPLIT);
+    }
+
+    let reversed: Vec<Vec<u8>> = images.into_iter().rev().collect();
+    let decoded = Steganography::decode_split_with_options(reversed, no_extract_options()).expect("failed joining the payload");
+    assert!(matches!(decoded.content, HiddenContent::Binary(joined) if joined == payload));
+}
+
+#[test]
+fn split_secret_round_trips_encrypted_and_error_corrected() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let options = EmbedOptions { password: Some("hunter2".to_string()), error_correction: 8, ..Default::default() };
+
+    let images = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(), bmp_bytes], options).expect("failed splitting the secret");
+    let decoded = Steganography::decode_split_with_options(images, ExtractOptions { password: Some("hunter2".to_string()), ..no_extract_options() }).expect("failed joining the secret");
+
+    assert!(matches!(decoded.content, HiddenContent::Text(secret) if secret == SECRET));
+}
+
+#[test]
+fn split_payload_missing_a_chunk_returns_incomplete_split() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let images = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 3], EmbedOptions::default()).unwrap();
+
+    let res = Steganography::decode_split_with_options(vec![images[2].clone(), images[0].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(reason)) if reason == "missing chunks 2 of 3"));
+
+    let res = Steganography::decode_with_options(images[0].clone(), no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(reason)) if reason == "missing chunks 2, 3 of 3"));
+
+    let res = Steganography::decode_split_with_options(vec![images[0].clone(), images[0].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+}
+
+#[test]
+fn chunks_of_different_split_payloads_return_incomplete_split() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let first = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], EmbedOptions::default()).unwrap();
+    let second = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], EmbedOptions::default()).unwrap();
+
+    let res = Steganography::decode_split_with_options(vec![first[0].clone(), second[1].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+
+    let single = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), EmbedOptions::default()).unwrap();
+    let res = Steganography::decode_split_with_options(vec![first[0].clone(), single], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+
+    let res = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes], EmbedOptions::default());
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+#[test]
+fn chunk_with_the_last_index_returns_corrupt_payload() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let images = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes; 2], EmbedOptions::default()).unwrap();
+
+    // the chunk index follows the 8 byte set ID
+    let crafted = rewrite_bmp_payload(&images[0], |_, chunk| chunk[8..10].copy_from_slice(&u16::MAX.to_le_bytes()));
+
+    let res = Steganography::decode_secret_with_options(crafted, no_extract_options());
+    assert!(matches!(res, Err(StegoError::CorruptPayload(_))), "{res:?}");
+}
+
+#[test]
+fn shared_secret_recovers_from_any_two_of_three_images() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let jpeg_bytes = std::fs::read("tests/data/test.jpeg").expect("couldn't read the non encoded image");
+    let png_bytes = std::fs::read("tests/data/test.png").expect("couldn't read the non encoded image");
+
+    let images = Steganography::encode_shared_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes, jpeg_bytes, png_bytes], 2, EmbedOptions::default()).expect("failed sharing the secret");
+    assert_eq!(images.len(), 3);
+
+    for image in &images {
+        let header = Steganography::probe(image.clone()).unwrap().header.expect("every image should hold a share");
+        assert_eq!(header.flags, PayloadFlags::SHARED);
+    }
+
+    for (first, second) in [(0, 1), (1, 2), (2, 0)] {
+        let decoded = Steganography::decode_split_with_options(vec![images[first].clone(), images[second].clone()], no_extract_options()).expect("failed recovering the secret");
+        assert!(matches!(decoded.content, HiddenContent::Text(secret) if secret == SECRET));
+    }
+
+    let res = Steganography::decode_with_options(images[1].clone(), no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(reason)) if reason == "1 of the 2 shares needed"));
+}
+
+#[test]
+fn shared_secret_round_trips_signed_and_encrypted() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let alice = Steganography::generate_signing_keypair();
+    let options = EmbedOptions { password: Some("hunter2".to_string()), ..signed_by(&alice) };
+
+    let images = Steganography::encode_shared_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes; 4], 3, options).expect("failed sharing the secret");
+    let extract_options = ExtractOptions { password: Some("hunter2".to_string()), ..no_extract_options() };
+    let decoded = Steganography::decode_split_with_options(images[1..].to_vec(), extract_options).expect("failed recovering the secret");
+
+    assert!(matches!(decoded.content, HiddenContent::Text(secret) if secret == SECRET));
+    assert!(matches!(decoded.signature, SignatureStatus::Valid(signer) if signer == alice.public_key));
+}
+
+#[test]
+fn sharing_with_a_threshold_out_of_range_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    for threshold in [0, 1, 3] {
+        let res = Steganography::encode_shared_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], threshold, EmbedOptions::default());
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+    }
+}
+
+#[test]
+fn shares_of_different_payloads_return_incomplete_split() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let first = Steganography::encode_shared_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], 2, EmbedOptions::default()).unwrap();
+    let second = Steganography::encode_shared_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], 2, EmbedOptions::default()).unwrap();
+
+    let res = Steganography::decode_split_with_options(vec![first[0].clone(), second[1].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+
+    let split = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes.clone(); 2], EmbedOptions::default()).unwrap();
+    let res = Steganography::decode_split_with_options(vec![first[0].clone(), split[1].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+
+    let res = Steganography::decode_split_with_options(vec![first[0].clone(), first[0].clone()], no_extract_options());
+    assert!(matches!(res, Err(StegoError::IncompleteSplit(_))));
+}
+
+fn behind_a_decoy(password: &str) -> EmbedOptions {
+    let decoy = Decoy { message: "buy milk".to_string(), password: "opensesame".to_string() };
+
+    EmbedOptions { password: Some(password.to_string()), decoy: Some(decoy), ..Default::default() }
+}
+
+#[test]
+fn decoy_password_extracts_the_decoy_and_the_other_one_the_secret() {
+    for image in [std::fs::read("tests/data/test.bmp").unwrap(), std::fs::read("tests/data/test.jpeg").unwrap(), std::fs::read("tests/data/test.png").unwrap()] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image, behind_a_decoy("hunter2")).expect("failed encoding the secret");
+
+        let decoded = Steganography::decode_secret_with_options(encoded.clone(), with_password("opensesame").1).expect("failed decoding the decoy");
+        assert_eq!(decoded.secret, "buy milk");
+
+        let decoded = Steganography::decode_secret_with_options(encoded.clone(), with_password("hunter2").1).expect("failed decoding the secret");
+        assert_eq!(decoded.secret, SECRET);
+
+        let res = Steganography::decode_secret_with_options(encoded, with_password("letmein").1);
+        assert!(matches!(res, Err(StegoError::WrongPassword)));
+    }
+}
+
+#[test]
+fn decoy_header_doesnt_tell_a_secret_hides_behind_it() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+    let (decoy_options, _) = with_password("opensesame");
+
+    let with_secret = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), behind_a_decoy("hunter2")).unwrap();
+    let decoy_alone = Steganography::encode_secret_with_options("buy milk".to_string(), bmp_bytes, decoy_options).unwrap();
+
+    let header = Steganography::probe(with_secret).unwrap().header.expect("the decoy header should be found");
+    let alone_header = Steganography::probe(decoy_alone).unwrap().header.unwrap();
+    assert_eq!(header.flags, alone_header.flags);
+    assert_eq!(header.length, alone_header.length);
+}
+
+#[test]
+fn decoy_and_secret_share_a_keyed_and_error_corrected_carrier() {
+    // both headers are stored three times, more than the test image holds
+    let mut bmp_bytes = Vec::new();
+    image::RgbImage::new(64, 64).write_to(&mut std::io::Cursor::new(&mut bmp_bytes), image::ImageFormat::Bmp).expect("failed encoding the bmp");
+    let options = EmbedOptions { key: Some("stego key".to_string()), error_correction: 4, ..behind_a_decoy("hunter2") };
+
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes, options).expect("failed encoding the secret");
+    let extract_options = |password: &str| ExtractOptions { key: Some("stego key".to_string()), ..with_password(password).1 };
+
+    assert_eq!(Steganography::decode_secret_with_options(encoded.clone(), extract_options("opensesame")).unwrap().secret, "buy milk");
+    assert_eq!(Steganography::decode_secret_with_options(encoded, extract_options("hunter2")).unwrap().secret, SECRET);
+}
+
+#[test]
+fn decoy_without_a_password_of_its_own_returns_invalid_option() {
+    let bmp_bytes = std::fs::read("tests/data/test.bmp").expect("couldn't read the non encoded image");
+
+    for options in [behind_a_decoy("opensesame"), EmbedOptions { password: None, ..behind_a_decoy("hunter2") }] {
+        let res = Steganography::encode_secret_with_options(SECRET.to_string(), bmp_bytes.clone(), options);
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+    }
+
+    let res = Steganography::encode_split_with_options(HiddenContent::Text(SECRET.to_string()), vec![bmp_bytes; 2], behind_a_decoy("hunter2"));
+    assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+}
+
+/// Baseline JPEG with the standard quantization and Huffman tables, as most cameras and editors write.
+fn baseline_jpeg() -> Vec<u8> {
+    let img = image::RgbImage::from_fn(256, 256, |x, y| image::Rgb([x as u8, y as u8, (x ^ y) as u8]));
+
+    let mut encoded = Vec::new();
+    image::DynamicImage::ImageRgb8(img).write_to(&mut std::io::Cursor::new(&mut encoded), image::ImageFormat::Jpeg).expect("failed encoding the image");
+
+    encoded
+}
+
+fn coefficients(jpeg: &[u8]) -> zune_jpeg::Coefficients {
+    zune_jpeg::JpegDecoder::new(jpeg).read_coefficients().expect("failed reading the coefficients")
+}
+
+#[test]
+fn encode_into_baseline_jpg_keeps_everything_but_its_scan() {
+    let image = baseline_jpeg();
+    let encoded = Steganography::encode_secret_into_jpeg(SECRET.to_string(), image.clone()).expect("failed encoding the secret");
+
+    // the quantization and Huffman tables, and every other header, come before the scan
+    let scan_start = coefficients(&image).scan_start;
+    assert_eq!(encoded[..scan_start], image[..scan_start]);
+    assert_eq!(encoded[encoded.len() - 2..], [0xFF, 0xD9]);
+
+    assert_eq!(Steganography::decode_secret_from_jpeg(encoded).expect("failed decoding the secret"), SECRET);
+}
+
+#[test]
+fn transcoding_baseline_jpg_without_a_secret_gives_back_its_bytes() {
+    let image = baseline_jpeg();
+    let transcoded = zune_image::codecs::jpeg::JpegTranscoder::new().transcode_with_scattered_secret(&image, &[]).expect("failed transcoding the image");
+
+    assert_eq!(transcoded, image);
+}
+
+#[test]
+fn encode_into_baseline_jpg_only_changes_carrier_coefficients() {
+    let image = baseline_jpeg();
+    let encoded = Steganography::encode_bytes_into_jpeg(incompressible(256), image.clone()).expect("failed encoding the payload");
+    let (original, transcoded) = (coefficients(&image), coefficients(&encoded));
+
+    assert_eq!(original.components[0].blocks, transcoded.components[0].blocks);
+
+    let mut changed_blocks = 0;
+
+    for (original, transcoded) in original.components[1..].iter().zip(&transcoded.components[1..]) {
+        for (before, after) in original.blocks.iter().zip(&transcoded.blocks) {
+            // J-Steg only takes the parity of the last 8 coefficients
+            assert_eq!(before[..56], after[..56]);
+            assert!(before[56..].iter().zip(&after[56..]).all(|(before, after)| (before - after).abs() <= 1));
+
+            changed_blocks += usize::from(before != after);
+        }
+    }
+
+    assert!(changed_blocks > 0);
+}
+
+#[test]
+fn capacity_of_baseline_jpg_counts_its_own_chroma_blocks() {
+    let image = baseline_jpeg();
+    let (embed_options, extract_options) = keyed("correct horse");
+
+    // every 8x8 MCU holds a block of each chroma component, less the header
+    let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity");
+    assert_eq!(capacity, 2 * 32 * 32 - 16);
+    assert_eq!(Steganography::probe(image.clone()).unwrap().capacity, capacity);
+
+    let payload = incompressible(capacity as usize);
+    let encoded = Steganography::encode_bytes_with_options(payload.clone(), image.clone(), embed_options.clone()).expect("a payload of the reported capacity should fit");
+    assert_eq!(Steganography::decode_bytes_with_options(encoded, extract_options).expect("failed decoding the payload").payload, payload);
+
+    let res = Steganography::encode_bytes_with_options(incompressible(capacity as usize + 1), image, embed_options);
+    assert!(matches!(res, Err(StegoError::CapacityExceeded)));
+}
+
+fn at_quality(quality: u8) -> EmbedOptions {
+    EmbedOptions { quality: Some(quality), ..Default::default() }
+}
+
+/// Values of the first quantization table of `jpeg`, in zigzag order.
+fn first_quantization_table(jpeg: &[u8]) -> &[u8] {
+    let dqt = jpeg.windows(2).position(|marker| marker == [0xFF, 0xDB]).expect("no quantization table");
+
+    &jpeg[dqt + 5..dqt + 5 + 64]
+}
+
+#[test]
+fn encode_into_jpg_round_trips_at_every_quality() {
+    let image = baseline_jpeg();
+
+    for quality in [1, 25, 50, 75, 90, 100] {
+        let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), image.clone(), at_quality(quality)).expect("failed encoding the secret");
+        assert_eq!(Steganography::decode_secret_from_jpeg(encoded).expect("failed decoding the secret"), SECRET, "quality {quality}");
+    }
+}
+
+#[test]
+fn encode_into_jpg_at_quality_50_uses_the_annex_k_tables() {
+    let encoded = Steganography::encode_secret_with_options(SECRET.to_string(), baseline_jpeg(), at_quality(50)).expect("failed encoding the secret");
+
+    assert_eq!(first_quantization_table(&encoded)[..8], [16, 11, 12, 14, 12, 10, 16, 14]);
+}
+
+#[test]
+fn encode_into_jpg_at_a_lower_quality_gives_a_smaller_image() {
+    let image = std::fs::read("tests/data/test.jpeg").expect("couldn't read the non encoded image");
+
+    let high = Steganography::encode_secret_with_options(SECRET.to_string(), image.clone(), at_quality(95)).expect("failed encoding the secret");
+    let low = Steganography::encode_secret_with_options(SECRET.to_string(), image.clone(), at_quality(50)).expect("failed encoding the secret");
+    assert!(low.len() < high.len());
+
+    // re-encoded at the default quality it is about as large as the photo it came from
+    let encoded = Steganography::encode_secret_into_jpeg(SECRET.to_string(), image.clone()).expect("failed encoding the secret");
+    assert!(encoded.len() < image.len() * 2, "{} bytes out of {}", encoded.len(), image.len());
+}
+
+#[test]
+fn jpeg_quality_out_of_range_returns_invalid_option() {
+    for quality in [0, 101] {
+        let res = Steganography::encode_secret_with_options(SECRET.to_string(), baseline_jpeg(), at_quality(quality));
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+
+        let res = Steganography::capacity_with_options(baseline_jpeg(), at_quality(quality));
+        assert!(matches!(res, Err(StegoError::InvalidOption(_))));
+    }
+}
+
+/// Sampling factors J-Steg output can have, luma against chroma, all of them in a single scan.
+const SAMPLING_FACTORS: [(zune_image::codecs::jpeg::SamplingFactor, usize, usize); 4] = {
+    use zune_image::codecs::jpeg::SamplingFactor::*;
+
+    [(F_1_1, 1, 1), (F_2_1, 2, 1), (F_1_2, 1, 2), (F_2_2, 2, 2)]
+};
+
+/// 200x120 image, sized so the last MCUs of a JPEG of it are partial whatever its subsampling.
+fn gradient_image() -> zune_image::image::Image {
+    let (width, height) = (200, 120);
+    let pixels: Vec<u8> = (0..width * height).flat_map(|i| [(i % width) as u8, (i / width) as u8, ((i % width) ^ (i / width)) as u8]).collect();
+
+    zune_image::image::Image::from_u8(&pixels, width, height, zune_jpeg::zune_core::colorspace::ColorSpace::RGB)
+}
+
+/// Encoder of JPEGs with their chroma subsampled as `sampling_factor` has it, progressive or not.
+fn jpeg_encoder(sampling_factor: zune_image::codecs::jpeg::SamplingFactor, progressive: bool) -> zune_image::codecs::jpeg::JpegEncoder {
+    let options = zune_jpeg::zune_core::options::EncoderOptions::default().set_jpeg_encode_progressive(progressive);
+    let mut encoder = zune_image::codecs::jpeg::JpegEncoder::new_with_options(options);
+    encoder.set_sampling_factor(sampling_factor);
+
+    encoder
+}
+
+/// Baseline JPEG of [`gradient_image`] with its chroma subsampled as `sampling_factor` has it.
+fn subsampled_jpeg(sampling_factor: zune_image::codecs::jpeg::SamplingFactor) -> Vec<u8> {
+    use zune_image::traits::EncoderTrait;
+
+    jpeg_encoder(sampling_factor, false).encode(&gradient_image()).expect("failed encoding the image")
+}
+
+/// Progressive JPEG of [`gradient_image`] with its chroma subsampled as `sampling_factor` has it.
+fn progressive_jpeg(sampling_factor: zune_image::codecs::jpeg::SamplingFactor) -> Vec<u8> {
+    use zune_image::traits::EncoderTrait;
+
+    jpeg_encoder(sampling_factor, true).encode(&gradient_image()).expect("failed encoding the image")
+}
+
+fn is_progressive(jpeg: &[u8]) -> bool {
+    let mut decoder = zune_jpeg::JpegDecoder::new(jpeg);
+    decoder.decode_headers().expect("failed decoding the headers");
+
+    decoder.info().unwrap().sof.is_progressive()
+}
+
+fn luma_sampling(jpeg: &[u8]) -> (usize, usize) {
+    let luma = &coefficients(jpeg).components[0];
+
+    (luma.horizontal_sample, luma.vertical_sample)
+}
+
+#[test]
+fn encode_into_subsampled_jpg_round_trips_with_every_sampling_factor() {
+    let (embed_options, extract_options) = keyed("correct horse");
+
+    for (sampling_factor, horizontal, vertical) in SAMPLING_FACTORS {
+        let image = subsampled_jpeg(sampling_factor);
+
+        // every MCU holds a block of each chroma component, less the header
+        let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity");
+        assert_eq!(capacity as usize, 2 * 200usize.div_ceil(8 * horizontal) * 120usize.div_ceil(8 * vertical) - 16, "{sampling_factor:?}");
+
+        for options in [embed_options.clone(), EmbedOptions { quality: Some(90), ..embed_options.clone() }] {
+            assert_eq!(Steganography::capacity_with_options(image.clone(), options.clone()).unwrap(), capacity, "{sampling_factor:?}");
+
+            let payload = incompressible(capacity as usize);
+            let encoded = Steganography::encode_bytes_with_options(payload.clone(), image.clone(), options).expect("a payload of the reported capacity should fit");
+            assert_eq!(luma_sampling(&encoded), (horizontal, vertical));
+
+            let decoded = Steganography::decode_bytes_with_options(encoded, extract_options.clone()).expect("failed decoding the payload");
+            assert!(decoded.payload == payload, "{sampling_factor:?}");
+        }
+    }
+}
+
+/// Baseline JPEG of a single luma component, whose blocks carry the secret.
+fn grayscale_jpeg() -> Vec<u8> {
+    let img = image::GrayImage::from_fn(100, 60, |x, y| image::Luma([(x * 2 + y) as u8]));
+
+    let mut encoded = Vec::new();
+    image::DynamicImage::ImageLuma8(img).write_to(&mut std::io::Cursor::new(&mut encoded), image::ImageFormat::Jpeg).expect("failed encoding the image");
+
+    encoded
+}
+
+#[test]
+fn encode_secret_into_grayscale_jpg_round_trips_and_stays_grayscale() {
+    let transcoded = Steganography::encode_secret_into_jpeg(SECRET.to_string(), grayscale_jpeg()).expect("failed encoding the secret");
+    let reencoded = Steganography::encode_secret_with_options(SECRET.to_string(), grayscale_jpeg(), at_quality(75)).expect("failed encoding the secret");
+
+    for encoded in [transcoded, reencoded] {
+        let decoded_img = image::load_from_memory_with_format(&encoded, image::ImageFormat::Jpeg).expect("the output should be a jpeg");
+        assert_eq!(decoded_img.color(), image::ColorType::L8);
+        assert_eq!(Steganography::decode_secret_from_jpeg(encoded).expect("failed decoding the secret"), SECRET);
+    }
+}
+
+#[test]
+fn capacity_of_grayscale_jpg_counts_its_luma_blocks() {
+    let image = grayscale_jpeg();
+    let (embed_options, extract_options) = keyed("correct horse");
+
+    // a byte in every 8x8 block, less the header
+    let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity");
+    assert_eq!(capacity, 13 * 8 - 16);
+
+    for options in [embed_options.clone(), EmbedOptions { quality: Some(90), ..embed_options }] {
+        let payload = incompressible(capacity as usize);
+        let encoded = Steganography::encode_bytes_with_options(payload.clone(), image.clone(), options).expect("a payload of the reported capacity should fit");
+
+        assert_eq!(Steganography::probe(encoded.clone()).expect("failed probing the image").capacity, capacity);
+
+        assert_eq!(Steganography::decode_bytes_with_options(encoded, extract_options.clone()).expect("failed decoding the payload").payload, payload);
+    }
+}
+
+#[test]
+fn encode_into_progressive_jpg_round_trips_and_stays_progressive() {
+    let (embed_options, extract_options) = keyed("correct horse");
+
+    for (sampling_factor, horizontal, vertical) in SAMPLING_FACTORS {
+        let image = progressive_jpeg(sampling_factor);
+        assert!(is_progressive(&image));
+
+        // the same carrier blocks as the baseline image
+        let capacity = Steganography::capacity_jpeg(image.clone()).expect("failed computing the capacity");
+        assert_eq!(capacity as usize, 2 * 200usize.div_ceil(8 * horizontal) * 120usize.div_ceil(8 * vertical) - 16, "{sampling_factor:?}");
+
+        for options in [embed_options.clone(), EmbedOptions { quality: Some(90), ..embed_options.clone() }] {
+            let payload = incompressible(capacity as usize);
+            let encoded = Steganography::encode_bytes_with_options(payload.clone(), image.clone(), options).expect("a payload of the reported capacity should fit");
+            assert!(is_progressive(&encoded), "{sampling_factor:?}");
+
+            assert_eq!(Steganography::probe(encoded.clone()).expect("failed probing the image").capacity, capacity);
+
+            let decoded = Steganography::decode_bytes_with_options(encoded, extract_options.clone()).expect("failed decoding the payload");
+            assert!(decoded.payload == payload, "{sampling_factor:?}");
+        }
+    }
+}
+
+#[test]
+fn baseline_and_progressive_jpg_carry_the_secret_in_the_same_blocks() {
+    use zune_image::traits::StegoEncoder;
+
+    let image = gradient_image();
+
+    for (sampling_factor, _, _) in SAMPLING_FACTORS {
+        let mut baseline_encoder = jpeg_encoder(sampling_factor, false);
+        let capacity = baseline_encoder.secret_capacity(200, 120, image.colorspace()).expect("failed computing the capacity");
+        // every other block left alone, as a keyed secret would
+        let secret: Vec<Option<u8>> = incompressible(capacity).into_iter().enumerate().map(|(i, byte)| (i % 2 == 0).then_some(byte)).collect();
+
+        let baseline = baseline_encoder.encode_with_scattered_secret(&image, &secret).expect("failed encoding the secret");
+        let progressive = jpeg_encoder(sampling_factor, true).encode_with_scattered_secret(&image, &secret).expect("failed encoding the secret");
+        assert!(is_progressive(&progressive));
+
+        let baseline_bytes = zune_jpeg::JpegDecoder::new(&baseline).read_secret(capacity).expect("failed reading the secret");
+        let progressive_bytes = zune_jpeg::JpegDecoder::new(&progressive).read_secret(capacity).expect("failed reading the secret");
+        assert_eq!(progressive_bytes.len(), capacity, "{sampling_factor:?}");
+        assert!(progressive_bytes == baseline_bytes, "{sampling_factor:?}");
+
+        for (read, written) in progressive_bytes.iter().zip(&secret) {
+            if let Some(written) = written {
+                assert_eq!(read, written, "{sampling_factor:?}");
+            }
+        }
+    }
+}
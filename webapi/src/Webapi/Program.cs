@@ -32,6 +32,19 @@ if (app.Environment.IsDevelopment())
 
 app.UseHttpsRedirection();
 
+// errors reported by the WASM module are caused by the request, not by the server
+app.Use(async (context, next) =>
+{
+    try
+    {
+        await next(context);
+    }
+    catch (StegoException ex)
+    {
+        await Results.UnprocessableEntity(ex.Message).ExecuteAsync(context);
+    }
+});
+
 app.MapPost("/encode", async ([FromBody] EncodeReq req, SteganographyWasmModule steganographyModule) =>
 {
     var (encodedImage, fileName) = req.MimeType.ToLowerInvariant() switch
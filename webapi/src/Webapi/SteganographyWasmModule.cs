@@ -61,12 +61,8 @@ public class SteganographyWasmModule : IDisposable
             // call the module's exported function
             int resultPtr = _decodeSecretFromBmp((int)imgPtr, (int)imgLen);
 
-            // read the result, encoded as a 32bit integer:
-            // - 2 high bytes represent the memory pointer to the result
-            int resultAddress = _memory.ReadInt32(resultPtr);
-
-            // - 2 low bytes represent the length of the data
-            int resultLength = _memory.ReadInt32(resultPtr + 4);
+            // read the result, throwing if the module returned an error
+            var (resultAddress, resultLength) = ReadResult(resultPtr);
 
             // get the decoded string encoded in the byte array pointed by the result
             var secret = _memory.ReadString(resultAddress, resultLength);
@@ -92,12 +88,8 @@ public class SteganographyWasmModule : IDisposable
             // call the module's exported function
             var resultPtr = _encodeSecretIntoBmp((int)secretPtr, (int)secretLen, (int)imgPtr, (int)imgLen);
 
-            // read the result, encoded as a 32bit integer:
-            // - 2 high bytes represent the memory pointer to the result
-            var resultAddress = _memory.ReadInt32(resultPtr);
-
-            // - 2 low bytes represent the length of the data
-            var resultLength = _memory.ReadInt32(resultPtr + 4);
+            // read the result, throwing if the module returned an error
+            var (resultAddress, resultLength) = ReadResult(resultPtr);
 
             // get the resulting byte array
             var outputImage = _memory.GetSpan(resultAddress, resultLength).ToArray();
@@ -122,12 +114,8 @@ public class SteganographyWasmModule : IDisposable
             // call the module's exported function
             int resultPtr = _decodeSecretFromJpeg((int)imgPtr, (int)imgLen);
 
-            // read the result, encoded as a 32bit integer:
-            // - 2 high bytes represent the memory pointer to the result
-            int resultAddress = _memory.ReadInt32(resultPtr);
-
-            // - 2 low bytes represent the length of the data
-            int resultLength = _memory.ReadInt32(resultPtr + 4);
+            // read the result, throwing if the module returned an error
+            var (resultAddress, resultLength) = ReadResult(resultPtr);
 
             // get the decoded string encoded in the byte array pointed by the result
             var secret = _memory.ReadString(resultAddress, resultLength);
@@ -153,12 +141,8 @@ public class SteganographyWasmModule : IDisposable
             // call the module's exported function
             var resultPtr = _encodeSecretIntoJpeg((int)secretPtr, (int)secretLen, (int)imgPtr, (int)imgLen);
 
-            // read the result, encoded as a 32bit integer:
-            // - 2 high bytes represent the memory pointer to the result
-            var resultAddress = _memory.ReadInt32(resultPtr);
-
-            // - 2 low bytes represent the length of the data
-            var resultLength = _memory.ReadInt32(resultPtr + 4);
+            // read the result, throwing if the module returned an error
+            var (resultAddress, resultLength) = ReadResult(resultPtr);
 
             // get the resulting byte array
             var outputImage = _memory.GetSpan(resultAddress, resultLength).ToArray();
@@ -171,6 +155,37 @@ public class SteganographyWasmModule : IDisposable
         }
     }
 
+    /// <summary>
+    /// Reads a <c>result&lt;list&lt;u8&gt;, stego-error&gt;</c> (or a string instead of the list) returned by the module.
+    /// </summary>
+    /// <remarks>
+    /// The result starts with its discriminant (1 byte padded to 4). On success it's followed by the
+    /// pointer and the length of the data. On error it's followed by the <c>stego-error</c> variant:
+    /// its case (1 byte padded to 4) and, for the cases carrying a reason, the pointer and length of the string.
+    /// </remarks>
+    private (int Address, int Length) ReadResult(int resultPtr)
+    {
+        if (_memory.ReadByte(resultPtr) != 0)
+        {
+            throw ReadStegoError(resultPtr + 4);
+        }
+
+        return (_memory.ReadInt32(resultPtr + 4), _memory.ReadInt32(resultPtr + 8));
+    }
+
+    private StegoException ReadStegoError(int errorPtr)
+    {
+        var kind = (StegoErrorKind)_memory.ReadByte(errorPtr);
+        var reason = kind switch
+        {
+            StegoErrorKind.UnsupportedFormat or StegoErrorKind.WrongPixelLayout or StegoErrorKind.CorruptPayload or StegoErrorKind.InvalidOption or StegoErrorKind.IncompleteSplit =>
+                _memory.ReadString(_memory.ReadInt32(errorPtr + 4), _memory.ReadInt32(errorPtr + 8)),
+            _ => null
+        };
+
+        return new StegoException(kind, reason);
+    }
+
     private int AllocateBytes(uint size)
     {
         long currentLength = _memory.GetLength();
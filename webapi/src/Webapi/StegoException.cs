@@ -0,0 +1,43 @@
+/// <summary>
+/// Cases of the <c>stego-error</c> variant declared in the WIT world, in declaration order.
+/// </summary>
+public enum StegoErrorKind : byte
+{
+    UnsupportedFormat,
+    WrongPixelLayout,
+    CapacityExceeded,
+    NoPayloadFound,
+    CorruptPayload,
+    InvalidUtf8,
+    InvalidOption,
+    PasswordRequired,
+    WrongPassword,
+    IdentityRequired,
+    NotARecipient,
+    IncompleteSplit,
+}
+
+/// <summary>
+/// Error returned by the WASM module while embedding or extracting a secret.
+/// </summary>
+public class StegoException(StegoErrorKind kind, string? reason) : Exception(Describe(kind, reason))
+{
+    public StegoErrorKind Kind { get; } = kind;
+
+    private static string Describe(StegoErrorKind kind, string? reason) => kind switch
+    {
+        StegoErrorKind.UnsupportedFormat => $"The image format is not supported: {reason}",
+        StegoErrorKind.WrongPixelLayout => $"The image can't carry a secret: {reason}",
+        StegoErrorKind.CapacityExceeded => "The secret is too long to fit into the image",
+        StegoErrorKind.NoPayloadFound => "The image doesn't contain a hidden secret",
+        StegoErrorKind.CorruptPayload => $"The hidden secret is damaged: {reason}",
+        StegoErrorKind.InvalidUtf8 => "The hidden secret is not valid UTF-8 text",
+        StegoErrorKind.InvalidOption => $"Invalid option: {reason}",
+        StegoErrorKind.PasswordRequired => "The hidden secret is encrypted, a password is required",
+        StegoErrorKind.WrongPassword => "The password doesn't decrypt the hidden secret",
+        StegoErrorKind.IdentityRequired => "The hidden secret is encrypted to recipients, an identity is required",
+        StegoErrorKind.NotARecipient => "None of the identities is a recipient of the hidden secret",
+        StegoErrorKind.IncompleteSplit => $"The images don't hold the whole split secret or enough shares of it: {reason}",
+        _ => $"Unknown error returned by the WASM module: {kind}"
+    };
+}
@@ -61,8 +61,10 @@ public class IntegrationTests
         req.Headers.ContentType = new System.Net.Http.Headers.MediaTypeHeaderValue("image/bmp");
         var response = await host.PostAsync("/decode", req, TestContext.Current.CancellationToken);
 
-        // wasm function should fail better... this means nothing.
-        Assert.Equal(System.Net.HttpStatusCode.InternalServerError, response.StatusCode);
+        Assert.Equal(System.Net.HttpStatusCode.UnprocessableEntity, response.StatusCode);
+
+        var errorContent = await response.Content.ReadAsStringAsync(TestContext.Current.CancellationToken);
+        Assert.Contains("hidden secret", errorContent);
     }
 
     [Fact]
@@ -121,8 +123,10 @@ public class IntegrationTests
         req.Headers.ContentType = new System.Net.Http.Headers.MediaTypeHeaderValue("image/jpeg");
         var response = await host.PostAsync("/decode", req, TestContext.Current.CancellationToken);
 
-        // wasm function should fail better... this means nothing.
-        Assert.Equal(System.Net.HttpStatusCode.InternalServerError, response.StatusCode);
+        Assert.Equal(System.Net.HttpStatusCode.UnprocessableEntity, response.StatusCode);
+
+        var errorContent = await response.Content.ReadAsStringAsync(TestContext.Current.CancellationToken);
+        Assert.Contains("hidden secret", errorContent);
     }
 }
 
@@ -96,4 +96,73 @@ describe('App', () => {
     expect(app.encode).toHaveBeenCalled();
     expect(app.saveToClient).toHaveBeenCalledWith(testImageBytes, 'test.bmp');
   });
+
+  it('should only offer decoding when the image carries a secret', async () => {
+    const fixture = TestBed.createComponent(App);
+    const app = fixture.componentInstance;
+    const img = await fetch('/base/fixtures/test-encoded.bmp');
+    const imgBytes = await img.bytes();
+    const mockFile = new File([imgBytes], 'test-encoded.bmp', { type: 'image/bmp' });
+    const mockEvt = { target: { files: [mockFile] } };
+
+    await app.onFileSelected(mockEvt as any);
+    fixture.detectChanges();
+    await fixture.whenStable();
+
+    expect(app.probeReport()?.header?.length).toBe(3);
+    expect(fixture.debugElement.query(By.css('input[type="button"][value="Decode"]'))).not.toBeNull();
+    expect(fixture.debugElement.query(By.css('input[type="button"][value="Encode"]'))).toBeNull();
+  });
+
+  it('should only decode an encrypted secret with its password', async () => {
+    const fixture = TestBed.createComponent(App);
+    const app = fixture.componentInstance;
+    let encodedBytes: Uint8Array | null = null;
+    spyOn(app, 'saveToClient').and.callFake((bytes: Uint8Array) => { encodedBytes = bytes; });
+    const select = async (bytes: Uint8Array, name: string) => {
+      await app.onFileSelected({ target: { files: [new File([bytes.slice()], name, { type: 'image/bmp' })] } } as any);
+      fixture.detectChanges();
+      await fixture.whenStable();
+    };
+
+    const img = await fetch('/base/fixtures/test.bmp');
+    await select(await img.bytes(), 'test.bmp');
+    app.password = 'hunter2';
+    app.secretToEncode = 'foo';
+    app.encode();
+    expect(encodedBytes).not.toBeNull();
+
+    await select(encodedBytes!, 'test.bmp');
+    expect(app.probeReport()?.header?.flags.encrypted).toBeTrue();
+
+    app.password = null;
+    app.decode();
+    expect(app.errorMessage()).toBe('The hidden secret is encrypted, enter its password');
+
+    app.password = 'hunter2';
+    app.decode();
+    expect(app.decodedSecret()).toBe('foo');
+  });
+
+  it('should offer a decoded file as a download under its name', async () => {
+    const fixture = TestBed.createComponent(App);
+    const app = fixture.componentInstance;
+    let encodedBytes: Uint8Array | null = null;
+    const saveSpy = spyOn(app, 'saveToClient').and.callFake((bytes: Uint8Array) => { encodedBytes = bytes; });
+    const content = new TextEncoder().encode('foo');
+
+    const img = await fetch('/base/fixtures/test.bmp');
+    await app.onFileSelected({ target: { files: [new File([await img.bytes()], 'test.bmp', { type: 'image/bmp' })] } } as any);
+    await app.onSecretFileSelected({ target: { files: [new File([content], 'notes.txt', { type: 'text/plain' })] } } as any);
+    app.encode();
+    expect(encodedBytes).not.toBeNull();
+
+    await app.onFileSelected({ target: { files: [new File([encodedBytes!.slice()], 'test.bmp', { type: 'image/bmp' })] } } as any);
+    expect(app.probeReport()?.header?.flags.file).toBeTrue();
+
+    app.decode();
+    expect(saveSpy).toHaveBeenCalledWith(content, 'notes.txt', 'text/plain');
+    expect(app.decodedFile()?.name).toBe('notes.txt');
+    expect(app.decodedSecret()).toBeNull();
+  });
 });
@@ -2,7 +2,7 @@ import { Component, signal, WritableSignal } from '@angular/core';
 import { RouterOutlet } from '@angular/router';
 import { DecimalPipe } from '@angular/common';
 import { FormsModule } from '@angular/forms';
-import { encodeSecretIntoBmp, decodeSecretFromBmp, encodeSecretIntoJpeg, decodeSecretFromJpeg } from '../bindings/wasm_steganography.js';
+import { encodeSecretWithOptions, encodeFileWithOptions, decodeWithOptions, probe, FilePayload, ProbeReport, SignatureStatus, StegoError } from '../bindings/wasm_steganography.js';
 import * as FileSaver from 'file-saver';
 
 @Component({
@@ -19,8 +19,19 @@ export class App {
   imageFileName: string | null = null;
   timeElapsedMs = signal(0);
   decodedSecret: WritableSignal<string | null> = signal(null);
+  /** Who signed the decoded secret, `null` when it wasn't signed. */
+  signatureMessage: WritableSignal<string | null> = signal(null);
+  /** File found in the image on decode, offered as a download instead of shown. */
+  decodedFile: WritableSignal<FilePayload | null> = signal(null);
   secretToEncode: string | null = null;
+  /** File embedded whole instead of the text secret when picked. */
+  fileToEncode: FilePayload | null = null;
+  /** Encrypts the secret on encode and decrypts it on decode, left out when empty. */
+  password: string | null = null;
   blobMimeType: string | null = null;
+  errorMessage: WritableSignal<string | null> = signal(null);
+  /** What the component found in the selected image, `null` if it couldn't tell. */
+  probeReport: WritableSignal<ProbeReport | null> = signal(null);
 
   async onFileSelected(event: Event): Promise<void> {
     const input = event.target as HTMLInputElement;
@@ -38,28 +49,83 @@ export class App {
       }
 
       this.imageUrl.set(URL.createObjectURL(imgBlob));
+      this.decodedSecret.set(null);
+      this.decodedFile.set(null);
+      this.signatureMessage.set(null);
+      this.errorMessage.set(null);
+      this.probeImage();
     }
   }
 
+  async onSecretFileSelected(event: Event): Promise<void> {
+    const input = event.target as HTMLInputElement;
+
+    if (input.files && input.files.length > 0) {
+      const file = input.files[0];
+      const content = new Uint8Array(await file.arrayBuffer());
+      // browsers leave the type empty when they can't tell it from the extension
+      this.fileToEncode = { name: file.name, mediaType: file.type || 'application/octet-stream', content };
+    }
+  }
+
+  /** Looks for a hidden secret so only the matching action, decode or encode, is offered. */
+  probeImage() {
+    try {
+      this.probeReport.set(probe(this.imageBytes!));
+    } catch (err) {
+      // the user still gets both actions and the real error once they pick one
+      console.error(err);
+      this.probeReport.set(null);
+    }
+  }
+
+  canDecode(): boolean {
+    const report = this.probeReport();
+
+    return report === null || report.header !== undefined;
+  }
+
+  canEncode(): boolean {
+    const report = this.probeReport();
+
+    return report === null || report.header === undefined;
+  }
+
   decode() {
     if (this.imageBytes == null) {
       throw Error("Image not defined");
     }
 
+    this.errorMessage.set(null);
+
     try {
+      // the component tells the image format from its content
       this.measureInMs(() => {
-        if (this.blobMimeType === 'image/jpeg' || this.blobMimeType === 'image/jpg') {
-          const secret = decodeSecretFromJpeg(this.imageBytes!);
-          this.decodedSecret.set(secret);
-        } else if (this.blobMimeType === 'image/bmp') {
-          const secret = decodeSecretFromBmp(this.imageBytes!);
-          this.decodedSecret.set(secret);
-        } else {
-          throw Error("Unsupported image format for decoding");
+        const decoded = decodeWithOptions(this.imageBytes!, { password: this.password || undefined, identities: [] });
+
+        switch (decoded.content.tag) {
+          case 'text':
+            this.decodedSecret.set(decoded.content.val);
+            break;
+          case 'binary':
+            this.saveToClient(decoded.content.val, 'secret.bin', 'application/octet-stream');
+            break;
+          case 'file':
+            this.decodedFile.set(decoded.content.val);
+            this.saveToClient(decoded.content.val.content, decoded.content.val.name, decoded.content.val.mediaType);
+            break;
+          case 'archive':
+            // archives are made with the CLI, every file is offered as its own download
+            for (const file of decoded.content.val) {
+              this.saveToClient(file.content, file.name, file.mediaType);
+            }
+            break;
         }
+        this.signatureMessage.set(describeSignature(decoded.signature));
       }, this.timeElapsedMs);
     } catch (err) {
       console.error(err);
+      this.errorMessage.set(describeError(err));
     }
   }
 
@@ -68,29 +134,32 @@ export class App {
       throw Error("Image not defined");
     }
 
-    if (this.secretToEncode == null) {
+    if (this.secretToEncode == null && this.fileToEncode == null) {
       throw Error("secret not defined");
     }
 
     this.decodedSecret.set(null);
+    this.decodedFile.set(null);
+    this.signatureMessage.set(null);
+    this.errorMessage.set(null);
 
     try {
       const encodedImgBytes = this.measureInMs(() => {
-        if (this.blobMimeType === 'image/jpeg' || this.blobMimeType === 'image/jpg') {
-          return encodeSecretIntoJpeg(this.secretToEncode!, this.imageBytes!);
-        } else if (this.blobMimeType === 'image/bmp') {
-          return encodeSecretIntoBmp(this.secretToEncode!, this.imageBytes!);
-        } else {
-          throw Error("Unsupported image format for encoding");
-        }
+        const options = { bitsPerChannel: 1, password: this.password || undefined, recipients: [], errorCorrection: 0 };
+
+        return this.fileToEncode != null
+          ? encodeFileWithOptions(this.fileToEncode, this.imageBytes!, options)
+          : encodeSecretWithOptions(this.secretToEncode!, this.imageBytes!, options);
       }, this.timeElapsedMs);
 
       this.saveToClient(encodedImgBytes, this.imageFileName!);
     } catch(err) {
       console.error(err);
+      this.errorMessage.set(describeError(err));
     }
 
     this.secretToEncode = null;
+    this.fileToEncode = null;
   }
 
   measureInMs<T>(fn: () => T, measurementDest: WritableSignal<number>): T {
@@ -103,8 +172,59 @@ export class App {
       return result;
   }
 
-  saveToClient = (bytes: Uint8Array, filename: string): void => {
-    const blob = new Blob([bytes.slice()], { type: this.blobMimeType ?? 'application/octet-stream' });
+  saveToClient = (bytes: Uint8Array, filename: string, mimeType?: string): void => {
+    const blob = new Blob([bytes.slice()], { type: mimeType ?? this.blobMimeType ?? 'application/octet-stream' });
     FileSaver.saveAs(blob, filename);
   }
 }
+
+/**
+ * Tells who signed a decoded secret, signing keys are only given through the CLI.
+ */
+function describeSignature(signature: SignatureStatus): string | null {
+  switch (signature.tag) {
+    case 'unsigned':
+      return null;
+    case 'valid':
+      return `Signed by ${signature.val}`;
+    case 'invalid':
+      return `Bad signature claiming to be from ${signature.val}, the secret was tampered with`;
+  }
+}
+
+/**
+ * Turns the error thrown by the WASM bindings into a message for the user.
+ * Errors returned by the component are thrown by jco with the `stego-error` as payload.
+ */
+function describeError(err: unknown): string {
+  const payload = (err as { payload?: StegoError }).payload;
+
+  switch (payload?.tag) {
+    case 'unsupported-format':
+      return `The image format is not supported: ${payload.val}`;
+    case 'wrong-pixel-layout':
+      return `The image can't carry a secret: ${payload.val}`;
+    case 'capacity-exceeded':
+      return 'The secret is too long to fit into the image';
+    case 'no-payload-found':
+      return "The image doesn't contain a hidden secret";
+    case 'corrupt-payload':
+      return `The hidden secret is damaged: ${payload.val}`;
+    case 'invalid-utf8':
+      return 'The hidden secret is not valid UTF-8 text';
+    case 'invalid-option':
+      return `Invalid option: ${payload.val}`;
+    case 'password-required':
+      return 'The hidden secret is encrypted, enter its password';
+    case 'wrong-password':
+      return "The password doesn't decrypt the hidden secret";
+    case 'identity-required':
+      return 'The hidden secret is encrypted to recipients, decode it with their identity in the CLI';
+    case 'not-a-recipient':
+      return 'None of the identities is a recipient of the hidden secret';
+    case 'incomplete-split':
+      return `The image only holds part of a secret split or shared across several images, recover it with the CLI: ${payload.val}`;
+    default:
+      return err instanceof Error ? err.message : String(err);
+  }
+}
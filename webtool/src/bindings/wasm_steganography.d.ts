@@ -12,9 +12,382 @@ export type * as WasiIoError023 from './interfaces/wasi-io-error.js'; // import
 export type * as WasiIoPoll023 from './interfaces/wasi-io-poll.js'; // import wasi:io/poll@0.2.3
 export type * as WasiIoStreams023 from './interfaces/wasi-io-streams.js'; // import wasi:io/streams@0.2.3
 export type * as WasiRandomRandom023 from './interfaces/wasi-random-random.js'; // import wasi:random/random@0.2.3
+/**
+ * Reasons why embedding or extracting a secret can fail.
+ */
+export type StegoError = StegoErrorUnsupportedFormat | StegoErrorWrongPixelLayout | StegoErrorCapacityExceeded | StegoErrorNoPayloadFound | StegoErrorCorruptPayload | StegoErrorInvalidUtf8 | StegoErrorInvalidOption | StegoErrorPasswordRequired | StegoErrorWrongPassword | StegoErrorIdentityRequired | StegoErrorNotARecipient | StegoErrorIncompleteSplit;
+/**
+ * The carrier couldn't be read or written as a supported image format.
+ */
+export interface StegoErrorUnsupportedFormat {
+  tag: 'unsupported-format',
+  val: string,
+}
+/**
+ * The image was decoded but its pixel layout can't carry a secret.
+ */
+export interface StegoErrorWrongPixelLayout {
+  tag: 'wrong-pixel-layout',
+  val: string,
+}
+/**
+ * The secret doesn't fit into the carrier.
+ */
+export interface StegoErrorCapacityExceeded {
+  tag: 'capacity-exceeded',
+}
+/**
+ * The carrier doesn't hold any hidden secret.
+ */
+export interface StegoErrorNoPayloadFound {
+  tag: 'no-payload-found',
+}
+/**
+ * A hidden secret was found but it is damaged or truncated.
+ */
+export interface StegoErrorCorruptPayload {
+  tag: 'corrupt-payload',
+  val: string,
+}
+/**
+ * The hidden secret isn't valid UTF-8 text.
+ */
+export interface StegoErrorInvalidUtf8 {
+  tag: 'invalid-utf8',
+}
+/**
+ * An embedding option is out of range.
+ */
+export interface StegoErrorInvalidOption {
+  tag: 'invalid-option',
+  val: string,
+}
+/**
+ * The hidden secret is encrypted and no password was given.
+ */
+export interface StegoErrorPasswordRequired {
+  tag: 'password-required',
+}
+/**
+ * The password doesn't decrypt the hidden secret.
+ */
+export interface StegoErrorWrongPassword {
+  tag: 'wrong-password',
+}
+/**
+ * The hidden secret is encrypted to recipients and no identity was given.
+ */
+export interface StegoErrorIdentityRequired {
+  tag: 'identity-required',
+}
+/**
+ * None of the identities given is a recipient of the hidden secret.
+ */
+export interface StegoErrorNotARecipient {
+  tag: 'not-a-recipient',
+}
+/**
+ * The images given don't hold every chunk of a split payload or enough shares of a shared one, or hold parts of
+ * different ones.
+ */
+export interface StegoErrorIncompleteSplit {
+  tag: 'incomplete-split',
+  val: string,
+}
+/**
+ * Harmless message to give away under coercion, hidden in the same image as the real secret.
+ */
+export interface Decoy {
+  /**
+   * Innocuous text its password extracts.
+   */
+  message: string,
+  /**
+   * Password that extracts the message, it must differ from the one of the real secret.
+   */
+  password: string,
+}
+/**
+ * Knobs for embedding a secret, the exports without options use the defaults.
+ */
+export interface EmbedOptions {
+  /**
+   * Least significant bits taken from every color sample of BMP and PNG images, 1 to 4. JPEG ignores it.
+   */
+  bitsPerChannel: number,
+  /**
+   * JPEG quality, 1 to 100, the standard quantization tables are scaled by. With it every JPEG is re-encoded at that
+   * quality, without it baseline JPEGs keep their own tables and any other is re-encoded at quality 80.
+   */
+  quality?: number,
+  /**
+   * Stego key picking the pixels or JPEG blocks that hold the secret, which are otherwise the first ones.
+   * The same key is needed to extract it.
+   */
+  key?: string,
+  /**
+   * Encrypts the secret with a key derived from this password before hiding it.
+   */
+  password?: string,
+  /**
+   * Public keys the secret is encrypted to before hiding it, only their identities can extract it.
+   * Can't be combined with a password.
+   */
+  recipients: Array<string>,
+  /**
+   * Signs the secret so receivers can tell who embedded it.
+   */
+  signingKey?: string,
+  /**
+   * Reed–Solomon parity bytes added to every block of up to 255 bytes, 0 to 128, 0 disables it.
+   * A block survives as many damaged bytes as half its parity. The header in front of the blocks is stored three
+   * times instead, so damage to one copy is outvoted.
+   */
+  errorCorrection: number,
+  /**
+   * Hides the secret, which must be encrypted with a password, behind this decoy. Nothing in the image tells there is
+   * more than the decoy, only the password of the secret finds it. Not supported with split or shared payloads.
+   */
+  decoy?: Decoy,
+}
+/**
+ * Knobs for extracting a secret, they must match the ones it was embedded with.
+ */
+export interface ExtractOptions {
+  /**
+   * Stego key the secret was embedded with, if any.
+   */
+  key?: string,
+  /**
+   * Password the secret was encrypted with, if any.
+   */
+  password?: string,
+  /**
+   * Identities tried on a secret encrypted to recipients.
+   */
+  identities: Array<string>,
+}
+/**
+ * X25519 key pair for encrypting secrets to a recipient.
+ */
+export interface Keypair {
+  /**
+   * Shared with whoever encrypts, starts with `wstg-pub-`.
+   */
+  publicKey: string,
+  /**
+   * Kept private to decrypt, starts with `WSTG-SECRET-KEY-`.
+   */
+  identity: string,
+}
+/**
+ * Ed25519 key pair for signing secrets.
+ */
+export interface SigningKeypair {
+  /**
+   * Shared with receivers to recognize the signer, starts with `wstg-sig-`.
+   */
+  publicKey: string,
+  /**
+   * Kept private to sign, starts with `WSTG-SIGNING-KEY-`.
+   */
+  signingKey: string,
+}
+/**
+ * Whether an extracted secret was signed and by whom.
+ */
+export type SignatureStatus = SignatureStatusUnsigned | SignatureStatusValid | SignatureStatusInvalid;
+export interface SignatureStatusUnsigned {
+  tag: 'unsigned',
+}
+/**
+ * Signed by the holder of this public key.
+ */
+export interface SignatureStatusValid {
+  tag: 'valid',
+  val: string,
+}
+/**
+ * Claims to be signed by this public key but the signature doesn't match, it was tampered with.
+ */
+export interface SignatureStatusInvalid {
+  tag: 'invalid',
+  val: string,
+}
+/**
+ * Text secret extracted with its signature status.
+ */
+export interface DecodedSecret {
+  secret: string,
+  signature: SignatureStatus,
+  /**
+   * Damaged bytes repaired by error correction.
+   */
+  correctedErrors: number,
+}
+/**
+ * Binary payload extracted with its signature status.
+ */
+export interface DecodedBytes {
+  payload: Uint8Array,
+  signature: SignatureStatus,
+  /**
+   * Damaged bytes repaired by error correction.
+   */
+  correctedErrors: number,
+}
+/**
+ * File embedded whole, with what's needed to write it back.
+ */
+export interface FilePayload {
+  /**
+   * Original file name, without any folder.
+   */
+  name: string,
+  /**
+   * MIME type of the content, like `image/png`.
+   */
+  mediaType: string,
+  content: Uint8Array,
+}
+/**
+ * What a payload holds, told by its header flags.
+ */
+export type HiddenContent = HiddenContentText | HiddenContentBinary | HiddenContentFile | HiddenContentArchive;
+export interface HiddenContentText {
+  tag: 'text',
+  val: string,
+}
+export interface HiddenContentBinary {
+  tag: 'binary',
+  val: Uint8Array,
+}
+export interface HiddenContentFile {
+  tag: 'file',
+  val: FilePayload,
+}
+/**
+ * Several files bundled together, in the order they were given.
+ */
+export interface HiddenContentArchive {
+  tag: 'archive',
+  val: Array<FilePayload>,
+}
+/**
+ * Payload extracted as whatever it was embedded as, with its signature status.
+ */
+export interface DecodedPayload {
+  content: HiddenContent,
+  signature: SignatureStatus,
+  /**
+   * Damaged bytes repaired by error correction.
+   */
+  correctedErrors: number,
+}
+/**
+ * Image formats that can carry a secret.
+ * 
+ * # Variants
+ * 
+ * ## `"bmp"`
+ * 
+ * ## `"jpeg"`
+ * 
+ * ## `"png"`
+ */
+export type CarrierFormat = 'bmp' | 'jpeg' | 'png';
+/**
+ * Features of a hidden payload, stored in its header.
+ */
+export interface PayloadFlags {
+  /**
+   * DEFLATE compressed, done whenever it makes the payload smaller.
+   */
+  compressed?: boolean,
+  encrypted?: boolean,
+  binary?: boolean,
+  recipients?: boolean,
+  signed?: boolean,
+  /**
+   * Split in Reed–Solomon blocks that repair damaged bytes.
+   */
+  errorCorrected?: boolean,
+  /**
+   * A whole file with its name and media type.
+   */
+  file?: boolean,
+  /**
+   * Several files, a directory of their names, media types and sizes followed by their contents.
+   */
+  archive?: boolean,
+  /**
+   * A chunk of a payload split across several images, preceded by its set ID, index and count.
+   */
+  split?: boolean,
+  /**
+   * A share of a payload, any threshold of which recover it, preceded by its set ID, number and threshold.
+   */
+  shared?: boolean,
+}
+/**
+ * Header found in front of a hidden payload.
+ */
+export interface PayloadHeader {
+  version: number,
+  flags: PayloadFlags,
+  /**
+   * Bits per channel of BMP and PNG payloads, 0 when it doesn't apply.
+   */
+  bitsPerChannel: number,
+  /**
+   * Payload size in bytes, header excluded.
+   */
+  length: number,
+}
+/**
+ * What an image is and whether it carries a secret.
+ */
+export interface ProbeReport {
+  format: CarrierFormat,
+  width: number,
+  height: number,
+  /**
+   * Payload bytes the image can hold, compressible payloads can be longer.
+   */
+  capacity: bigint,
+  /**
+   * Only set when a valid payload header was found.
+   */
+  header?: PayloadHeader,
+}
 export function encodeSecretIntoBmp(secret: string, image: Uint8Array): Uint8Array;
 export function decodeSecretFromBmp(image: Uint8Array): string;
+export function encodeBytesIntoBmp(payload: Uint8Array, image: Uint8Array): Uint8Array;
+export function decodeBytesFromBmp(image: Uint8Array): Uint8Array;
+export function capacityBmp(image: Uint8Array): bigint;
 export function encodeSecretIntoJpeg(secret: string, image: Uint8Array): Uint8Array;
 export function decodeSecretFromJpeg(image: Uint8Array): string;
+export function encodeBytesIntoJpeg(payload: Uint8Array, image: Uint8Array): Uint8Array;
+export function decodeBytesFromJpeg(image: Uint8Array): Uint8Array;
+export function capacityJpeg(image: Uint8Array): bigint;
+export function encodeSecretIntoPng(secret: string, image: Uint8Array): Uint8Array;
+export function decodeSecretFromPng(image: Uint8Array): string;
+export function encodeBytesIntoPng(payload: Uint8Array, image: Uint8Array): Uint8Array;
+export function decodeBytesFromPng(image: Uint8Array): Uint8Array;
+export function capacityPng(image: Uint8Array): bigint;
+export function encodeSecretWithOptions(secret: string, image: Uint8Array, options: EmbedOptions): Uint8Array;
+export function encodeBytesWithOptions(payload: Uint8Array, image: Uint8Array, options: EmbedOptions): Uint8Array;
+export function encodeFileWithOptions(file: FilePayload, image: Uint8Array, options: EmbedOptions): Uint8Array;
+export function encodeArchiveWithOptions(files: Array<FilePayload>, image: Uint8Array, options: EmbedOptions): Uint8Array;
+export function encodeSplitWithOptions(content: HiddenContent, images: Array<Uint8Array>, options: EmbedOptions): Array<Uint8Array>;
+export function encodeSharedWithOptions(content: HiddenContent, images: Array<Uint8Array>, threshold: number, options: EmbedOptions): Array<Uint8Array>;
+export function capacityWithOptions(image: Uint8Array, options: EmbedOptions): bigint;
+export function decodeSecretWithOptions(image: Uint8Array, options: ExtractOptions): DecodedSecret;
+export function decodeBytesWithOptions(image: Uint8Array, options: ExtractOptions): DecodedBytes;
+export function decodeWithOptions(image: Uint8Array, options: ExtractOptions): DecodedPayload;
+export function decodeSplitWithOptions(images: Array<Uint8Array>, options: ExtractOptions): DecodedPayload;
+export function generateKeypair(): Keypair;
+export function generateSigningKeypair(): SigningKeypair;
+export function probe(image: Uint8Array): ProbeReport;
 
 export const $init: Promise<void>;